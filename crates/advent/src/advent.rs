@@ -0,0 +1,174 @@
+use advent::inputs::{self, default_input};
+use advent::{find, find_both, PUZZLES};
+use advent_core::{input, ParseError, Part, Timed};
+use format::{print_extras, Format, Record};
+use std::process::ExitCode;
+
+mod bench;
+mod format;
+mod verify;
+mod vm;
+mod warehouse;
+
+fn usage() -> ExitCode {
+    eprintln!("usage: advent [run] <day> <a|b|both> [<input>|-]... [--format text|verbose|json]");
+    eprintln!("       advent run --all [--format text|verbose|json]");
+    eprintln!("       advent list");
+    eprintln!("       advent verify [<day>...]");
+    eprintln!("       advent vm [--trace] [--budget <n>] [--break <ip>]... [<input>|-]");
+    eprintln!("       advent vm --disasm|--decompile [<input>|-]");
+    eprintln!("       advent vm --asm [<source>|-]");
+    eprintln!("       advent warehouse [--wide|--scale <h>x<w>] [--script] <input>");
+    eprintln!(
+        "       advent warehouse --replay [--speed <moves/s>] [--wide|--scale <h>x<w>] <input>"
+    );
+    eprintln!(
+        "       advent warehouse --frames text|ansi|ppm|pgm [--out <path>] [--pixels <n>] <input>"
+    );
+    eprintln!("       advent bench [<day>[a|b]...] [--inputs input|examples|all]");
+    eprintln!("                    [--warmup <n>] [--samples <n>] [--json]");
+    eprintln!("                    [--save <file>] [--baseline <file>] [--threshold <percent>]");
+    ExitCode::FAILURE
+}
+
+/// `-` is stdin, which is also what's read when no inputs are given.
+fn read(input: &str) -> std::io::Result<String> {
+    if input == "-" {
+        input::read_stdin()
+    } else {
+        input::read(input)
+    }
+}
+
+/// Runs one or both parts of a day on an input.
+type Runner = dyn Fn(&str) -> Result<Vec<(Part, Timed)>, ParseError>;
+
+fn run_one(day: &str, part: &str, inputs: &[&str], format: Format) -> ExitCode {
+    let Ok(day) = day.parse() else {
+        return usage();
+    };
+    // both parts from one parse
+    let run: Box<Runner> = if part == "both" {
+        let Some(both) = find_both(day) else {
+            eprintln!("day {day} doesn't have both parts solved");
+            return ExitCode::FAILURE;
+        };
+        Box::new(|s| {
+            let [a, b] = both.run(s)?;
+            Ok(vec![(Part::A, a), (Part::B, b)])
+        })
+    } else {
+        let Some(part) = Part::parse(part) else {
+            return usage();
+        };
+        let Some(entry) = find(day, part) else {
+            eprintln!("no solution for day {day} part {part}");
+            return ExitCode::FAILURE;
+        };
+        Box::new(move |s| Ok(vec![(part, entry.timed(s)?)]))
+    };
+
+    let inputs = if inputs.is_empty() { &["-"] } else { inputs };
+    let mut ok = true;
+    for &input in inputs {
+        let label = if input == "-" { "<stdin>" } else { input };
+        let data = match read(input) {
+            Ok(data) => data,
+            Err(e) => {
+                eprintln!("{label}: {e}");
+                ok = false;
+                continue;
+            }
+        };
+        let answers = match run(&data) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{}", e.report(label, &data));
+                ok = false;
+                continue;
+            }
+        };
+        for (part, t) in &answers {
+            let answer = match answers.len() {
+                1 => t.answer.clone(),
+                _ => format!("{part}: {}", t.answer),
+            };
+            match format {
+                Format::Json => Record::new(day, *part, input, t).print(),
+                // answers are only labeled when there's more than one input to tell apart
+                Format::Text | Format::Verbose if inputs.len() == 1 => println!("{answer}"),
+                Format::Text | Format::Verbose => println!("{label}: {answer}"),
+            }
+            if format == Format::Verbose {
+                print_extras(t);
+            }
+        }
+    }
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_all(format: Format) -> ExitCode {
+    let mut ok = true;
+    for entry in PUZZLES {
+        let input = default_input(entry.day);
+        let label = inputs::label(&input);
+        match input::read(&input) {
+            Ok(data) => match entry.timed(&data) {
+                Ok(t) => match format {
+                    Format::Json => Record::new(entry.day, entry.part, &label, &t).print(),
+                    Format::Text | Format::Verbose => {
+                        println!("{:>2}{}: {}", entry.day, entry.part, t.answer);
+                        if format == Format::Verbose {
+                            print_extras(&t);
+                        }
+                    }
+                },
+                Err(e) => {
+                    ok = false;
+                    eprintln!("{:>2}{}: {}: {e}", entry.day, entry.part, input.display());
+                }
+            },
+            Err(e) => eprintln!(
+                "{:>2}{}: skipped ({}: {e})",
+                entry.day,
+                entry.part,
+                input.display()
+            ),
+        }
+    }
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<_> = std::env::args().skip(1).collect();
+    let args: Vec<_> = args.iter().map(String::as_str).collect();
+    let Some((format, args)) = Format::take(&args) else {
+        return usage();
+    };
+    match args.as_slice() {
+        ["list"] => {
+            for entry in PUZZLES {
+                println!("{:>2}{}", entry.day, entry.part);
+            }
+            ExitCode::SUCCESS
+        }
+        ["run", "--all"] => run_all(format),
+        // `run` can be left out, e.g. `cat input.txt | advent 11 b`
+        ["run", day, part, inputs @ ..] | [day, part, inputs @ ..] if day.parse::<u8>().is_ok() => {
+            run_one(day, part, inputs, format)
+        }
+        ["bench", args @ ..] => bench::main(PUZZLES, args).unwrap_or_else(usage),
+        ["verify", days @ ..] => verify::main(days).unwrap_or_else(usage),
+        ["vm", args @ ..] => vm::main(args).unwrap_or_else(usage),
+        ["warehouse", args @ ..] => warehouse::main(args).unwrap_or_else(usage),
+        _ => usage(),
+    }
+}
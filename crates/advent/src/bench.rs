@@ -0,0 +1,282 @@
+//! `advent bench`: times each day's parse and solve phases separately over repeated runs.
+
+use advent::inputs;
+use advent_core::{input, Entry, Part};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Inputs {
+    Input,
+    Examples,
+    All,
+}
+
+struct Options {
+    days: Vec<(u8, Option<Part>)>,
+    inputs: Inputs,
+    warmup: usize,
+    samples: usize,
+    json: bool,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+}
+
+impl Options {
+    fn parse(args: &[&str]) -> Option<Self> {
+        let mut o = Options {
+            days: vec![],
+            inputs: Inputs::Input,
+            warmup: 3,
+            samples: 10,
+            json: false,
+            save: None,
+            baseline: None,
+            threshold: 10.0,
+        };
+        let mut args = args.iter();
+        while let Some(&arg) = args.next() {
+            match arg {
+                "--inputs" => {
+                    o.inputs = match *args.next()? {
+                        "input" => Inputs::Input,
+                        "examples" => Inputs::Examples,
+                        "all" => Inputs::All,
+                        _ => return None,
+                    }
+                }
+                "--warmup" => o.warmup = args.next()?.parse().ok()?,
+                "--samples" => o.samples = args.next()?.parse().ok().filter(|&n| n > 0)?,
+                "--json" => o.json = true,
+                "--save" => o.save = Some(args.next()?.into()),
+                "--baseline" => o.baseline = Some(args.next()?.into()),
+                "--threshold" => o.threshold = args.next()?.parse().ok()?,
+                day => {
+                    let (day, part) = match day.strip_suffix(['a', 'b']) {
+                        Some(d) => (d, Part::parse(&day[d.len()..])),
+                        None => (day, None),
+                    };
+                    o.days.push((day.parse().ok()?, part));
+                }
+            }
+        }
+        Some(o)
+    }
+
+    fn wants(&self, entry: &Entry) -> bool {
+        self.days.is_empty()
+            || self
+                .days
+                .iter()
+                .any(|&(d, p)| d == entry.day && p.is_none_or(|p| p == entry.part))
+    }
+
+    fn wants_file(&self, path: &Path) -> bool {
+        let input = path.ends_with("input.txt");
+        match self.inputs {
+            Inputs::Input => input,
+            Inputs::Examples => !input,
+            Inputs::All => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl Stats {
+    fn new(samples: &[Duration]) -> Self {
+        let mut ns: Vec<_> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        ns.sort_by(f64::total_cmp);
+        let n = ns.len() as f64;
+        let mean = ns.iter().sum::<f64>() / n;
+        let mid = ns.len() / 2;
+        let median = if ns.len() % 2 == 0 {
+            (ns[mid - 1] + ns[mid]) / 2.0
+        } else {
+            ns[mid]
+        };
+        let var = ns.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0).max(1.0);
+        Stats {
+            mean_ns: mean,
+            median_ns: median,
+            stddev_ns: var.sqrt(),
+        }
+    }
+}
+
+/// One line of the report, and one entry of a saved baseline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: String,
+    pub input: String,
+    pub samples: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl Report {
+    fn same_run(&self, rhs: &Report) -> bool {
+        self.day == rhs.day && self.part == rhs.part && self.input == rhs.input
+    }
+
+    /// Phases whose median got slower than `base` by more than `threshold` percent, with
+    /// how much slower.
+    fn regressions(&self, base: &Report, threshold: f64) -> Vec<(&'static str, f64)> {
+        [
+            ("parse", self.parse, base.parse),
+            ("solve", self.solve, base.solve),
+        ]
+        .into_iter()
+        .filter_map(|(phase, new, old)| {
+            let change = (new.median_ns / old.median_ns - 1.0) * 100.0;
+            (change > threshold).then_some((phase, change))
+        })
+        .collect()
+    }
+}
+
+fn format_ns(ns: f64) -> String {
+    if ns < 1e3 {
+        format!("{ns:.0}ns")
+    } else if ns < 1e6 {
+        format!("{:.1}µs", ns / 1e3)
+    } else if ns < 1e9 {
+        format!("{:.1}ms", ns / 1e6)
+    } else {
+        format!("{:.2}s", ns / 1e9)
+    }
+}
+
+fn measure(entry: &Entry, path: &Path, o: &Options) -> Result<Report, String> {
+    let label = inputs::label(path);
+    let data = input::read(path).map_err(|e| format!("{label}: {e}"))?;
+    let mut parse = vec![];
+    let mut solve = vec![];
+    for i in 0..o.warmup + o.samples {
+        let t = entry
+            .timed(&data)
+            .map_err(|e| format!("{}", e.report(&label, &data)))?;
+        if i >= o.warmup {
+            parse.push(t.parse);
+            solve.push(t.solve);
+        }
+    }
+    Ok(Report {
+        day: entry.day,
+        part: entry.part.to_string(),
+        input: label,
+        samples: o.samples,
+        parse: Stats::new(&parse),
+        solve: Stats::new(&solve),
+    })
+}
+
+fn print_header() {
+    println!(
+        "{:>4}  {:<26} {:>10} {:>10} {:>10}   {:>10} {:>10} {:>10}",
+        "day", "input", "parse mean", "median", "stddev", "solve mean", "median", "stddev"
+    );
+}
+
+fn print_row(r: &Report, regressions: &[(&str, f64)]) {
+    let s = |s: Stats| {
+        format!(
+            "{:>10} {:>10} {:>10}",
+            format_ns(s.mean_ns),
+            format_ns(s.median_ns),
+            format_ns(s.stddev_ns)
+        )
+    };
+    let flags: String = regressions
+        .iter()
+        .map(|(phase, change)| format!("  {phase} +{change:.0}%"))
+        .collect();
+    println!(
+        "{:>3}{}  {:<26} {}   {}{flags}",
+        r.day,
+        r.part,
+        r.input,
+        s(r.parse),
+        s(r.solve)
+    );
+}
+
+pub fn main(puzzles: &[Entry], args: &[&str]) -> Option<ExitCode> {
+    let o = Options::parse(args)?;
+    let baseline: Vec<Report> = match &o.baseline {
+        Some(path) => {
+            let loaded = fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|s| serde_json::from_str(&s).map_err(|e| e.to_string()));
+            match loaded {
+                Ok(b) => b,
+                Err(e) => {
+                    eprintln!("{}: {e}", path.display());
+                    return Some(ExitCode::FAILURE);
+                }
+            }
+        }
+        None => vec![],
+    };
+
+    if !o.json {
+        print_header();
+    }
+    let mut ok = true;
+    let mut reports = vec![];
+    for entry in puzzles.iter().filter(|e| o.wants(e)) {
+        for path in inputs::input_files(entry.day) {
+            if !o.wants_file(&path) {
+                continue;
+            }
+            let r = match measure(entry, &path, &o) {
+                Ok(r) => r,
+                Err(e) => {
+                    eprintln!("{e}");
+                    ok = false;
+                    continue;
+                }
+            };
+            let regressions = baseline
+                .iter()
+                .find(|b| b.same_run(&r))
+                .map(|b| r.regressions(b, o.threshold))
+                .unwrap_or_default();
+            ok &= regressions.is_empty();
+            if !o.json {
+                print_row(&r, &regressions);
+            } else {
+                for (phase, change) in &regressions {
+                    eprintln!("{}{} {}: {phase} +{change:.0}%", r.day, r.part, r.input);
+                }
+            }
+            reports.push(r);
+        }
+    }
+
+    let json = serde_json::to_string_pretty(&reports).unwrap();
+    if o.json {
+        println!("{json}");
+    }
+    if let Some(path) = &o.save {
+        if let Err(e) = fs::write(path, json + "\n") {
+            eprintln!("{}: {e}", path.display());
+            ok = false;
+        }
+    }
+    Some(if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,82 @@
+//! `--format`: plain answers for people, optionally with whatever else the answer reports, or
+//! one JSON object per line for scripts.
+
+use advent_core::{Part, Timed};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// Text followed by the answer's extras.
+    Verbose,
+    Json,
+}
+
+impl Format {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(Format::Text),
+            "verbose" => Some(Format::Verbose),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+
+    /// Pulls `--format <text|verbose|json>` out of `args`, wherever it is. `None` if the format is
+    /// missing or unknown.
+    pub fn take<'a>(args: &[&'a str]) -> Option<(Self, Vec<&'a str>)> {
+        let Some(i) = args.iter().position(|&a| a == "--format") else {
+            return Some((Format::Text, args.to_vec()));
+        };
+        let format = Self::parse(args.get(i + 1)?)?;
+        let rest = args[..i].iter().chain(&args[i + 2..]).copied().collect();
+        Some((format, rest))
+    }
+}
+
+/// Prints an answer's extras under it for `--format verbose`, on lines of their own when
+/// they're more than one line long, like a map.
+pub fn print_extras(t: &Timed) {
+    for (k, v) in &t.extra {
+        if v.contains('\n') {
+            print!("{k}:\n{v}");
+            if !v.ends_with('\n') {
+                println!();
+            }
+        } else {
+            println!("{k}: {v}");
+        }
+    }
+}
+
+/// One answer in `--format json`. The answer is always a string so big numbers survive
+/// whatever reads it.
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: String,
+    pub input: &'a str,
+    pub answer: &'a str,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    pub extra: BTreeMap<&'static str, &'a str>,
+}
+
+impl<'a> Record<'a> {
+    pub fn new(day: u8, part: Part, input: &'a str, t: &'a Timed) -> Self {
+        Record {
+            day,
+            part: part.to_string(),
+            input,
+            answer: &t.answer,
+            parse_ns: t.parse.as_nanos() as u64,
+            solve_ns: t.solve.as_nanos() as u64,
+            extra: t.extra.iter().map(|(k, v)| (*k, v.as_str())).collect(),
+        }
+    }
+
+    pub fn print(&self) {
+        println!("{}", serde_json::to_string(self).unwrap());
+    }
+}
@@ -0,0 +1,53 @@
+//! Finding the inputs checked in next to each day's crate.
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+fn crates_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+fn crate_dir(day: u8) -> PathBuf {
+    crates_dir().join(format!("puzzle_{day}"))
+}
+
+pub fn default_input(day: u8) -> PathBuf {
+    crate_dir(day).join("input.txt")
+}
+
+/// `input.txt` and every `example*.txt` for a day, examples first.
+pub fn input_files(day: u8) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(crate_dir(day)) else {
+        return vec![];
+    };
+    let mut files: Vec<_> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            let name = p.file_name().unwrap_or_default().to_string_lossy();
+            name == "input.txt" || (name.starts_with("example") && name.ends_with(".txt"))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// `path` relative to the crates directory when it's one of the checked-in files, e.g.
+/// `puzzle_16/example1.txt`, so reports don't depend on where the repo is cloned.
+pub fn label(path: &Path) -> String {
+    // manifests can point elsewhere with `../`, which shouldn't show up in the label
+    let mut clean = PathBuf::new();
+    for c in path.components() {
+        match c {
+            Component::ParentDir => {
+                clean.pop();
+            }
+            c => clean.push(c),
+        }
+    }
+    clean
+        .strip_prefix(crates_dir())
+        .unwrap_or(&clean)
+        .display()
+        .to_string()
+}
@@ -0,0 +1,88 @@
+//! Every day's solution in one table, shared by the `advent` binary and the answer tests.
+
+use advent_core::{Both, Entry, Part};
+
+pub mod inputs;
+pub mod manifest;
+
+pub const PUZZLES: &[Entry] = &[
+    Entry::a::<puzzle_1::Solution>(1),
+    Entry::b::<puzzle_1::Solution>(1),
+    Entry::a::<puzzle_2::Solution>(2),
+    Entry::b::<puzzle_2::Solution>(2),
+    Entry::a::<puzzle_3::Solution>(3),
+    Entry::b::<puzzle_3::Solution>(3),
+    Entry::a::<puzzle_4::Solution>(4),
+    Entry::b::<puzzle_4::Solution>(4),
+    Entry::a::<puzzle_5::Solution>(5),
+    Entry::b::<puzzle_5::Solution>(5),
+    Entry::a::<puzzle_6::Solution>(6),
+    Entry::b::<puzzle_6::Solution>(6),
+    Entry::a::<puzzle_7::Solution>(7),
+    Entry::b::<puzzle_7::Solution>(7),
+    Entry::a::<puzzle_8::Solution>(8),
+    Entry::b::<puzzle_8::Solution>(8),
+    Entry::a::<puzzle_9::Solution>(9),
+    Entry::b::<puzzle_9::Solution>(9),
+    Entry::a::<puzzle_10::Solution>(10),
+    Entry::b::<puzzle_10::Solution>(10),
+    Entry::a::<puzzle_11::Solution>(11),
+    Entry::b::<puzzle_11::Solution>(11),
+    Entry::a::<puzzle_12::Solution>(12),
+    Entry::b::<puzzle_12::Solution>(12),
+    Entry::a::<puzzle_13::Solution>(13),
+    Entry::b::<puzzle_13::Solution>(13),
+    Entry::a::<puzzle_14::Solution>(14),
+    Entry::b::<puzzle_14::Solution>(14),
+    Entry::a::<puzzle_15::Solution>(15),
+    Entry::b::<puzzle_15::Solution>(15),
+    Entry::a::<puzzle_16::Solution>(16),
+    Entry::b::<puzzle_16::Solution>(16),
+    Entry::a::<puzzle_17::Solution>(17),
+    Entry::b::<puzzle_17::Solution>(17),
+    Entry::a::<puzzle_18::Solution>(18),
+    Entry::b::<puzzle_18::Solution>(18),
+    Entry::a::<puzzle_19::Solution>(19),
+    Entry::b::<puzzle_19::Solution>(19),
+    Entry::a::<puzzle_20::Solution>(20),
+    Entry::b::<puzzle_20::Solution>(20),
+];
+
+/// The days with both parts solved, run from a single parse.
+pub const BOTH: &[Both] = &[
+    Both::new::<puzzle_1::Solution>(1),
+    Both::new::<puzzle_2::Solution>(2),
+    Both::new::<puzzle_3::Solution>(3),
+    Both::new::<puzzle_4::Solution>(4),
+    Both::new::<puzzle_5::Solution>(5),
+    Both::new::<puzzle_6::Solution>(6),
+    Both::new::<puzzle_7::Solution>(7),
+    Both::new::<puzzle_8::Solution>(8),
+    Both::new::<puzzle_9::Solution>(9),
+    Both::new::<puzzle_10::Solution>(10),
+    Both::new::<puzzle_11::Solution>(11),
+    Both::new::<puzzle_12::Solution>(12),
+    Both::new::<puzzle_13::Solution>(13),
+    Both::new::<puzzle_14::Solution>(14),
+    Both::new::<puzzle_15::Solution>(15),
+    Both::new::<puzzle_16::Solution>(16),
+    Both::new::<puzzle_17::Solution>(17),
+    Both::new::<puzzle_18::Solution>(18),
+    Both::new::<puzzle_19::Solution>(19),
+    Both::new::<puzzle_20::Solution>(20),
+];
+
+pub fn find(day: u8, part: Part) -> Option<&'static Entry> {
+    PUZZLES.iter().find(|e| e.day == day && e.part == part)
+}
+
+pub fn find_both(day: u8) -> Option<&'static Both> {
+    BOTH.iter().find(|b| b.day == day)
+}
+
+/// Every day with at least one part solved, in order.
+pub fn days() -> impl Iterator<Item = u8> {
+    let mut days: Vec<_> = PUZZLES.iter().map(|e| e.day).collect();
+    days.dedup();
+    days.into_iter()
+}
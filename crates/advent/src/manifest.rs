@@ -0,0 +1,134 @@
+//! The known answers for each day, checked in as `puzzle_<day>/answers.toml`:
+//!
+//! ```toml
+//! [[input]]
+//! file = "example.txt"
+//! a = "11"
+//! b = "31"
+//! ```
+//!
+//! `file` is relative to the manifest, and either answer can be left out when it isn't known
+//! or doesn't apply to that file.
+
+use crate::{find, inputs};
+use advent_core::{input, Part};
+use serde::Deserialize;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Deserialize)]
+pub struct Manifest {
+    #[serde(rename = "input")]
+    pub inputs: Vec<Expected>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub file: String,
+    pub a: Option<String>,
+    pub b: Option<String>,
+}
+
+impl Expected {
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::A => self.a.as_deref(),
+            Part::B => self.b.as_deref(),
+        }
+    }
+}
+
+pub fn path(day: u8) -> PathBuf {
+    inputs::default_input(day).with_file_name("answers.toml")
+}
+
+/// `None` if the day has no manifest.
+pub fn load(day: u8) -> Result<Option<Manifest>, String> {
+    let path = path(day);
+    let s = match fs::read_to_string(&path) {
+        Ok(s) => s,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("{}: {e}", path.display())),
+    };
+    toml::from_str(&s)
+        .map(Some)
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Wrong(String),
+    /// The input couldn't be read or parsed, or there's no solution for that part.
+    Error(String),
+}
+
+/// One part run against one input from a manifest.
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub expected: String,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.outcome == Outcome::Pass
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:>2}{} {}: ", self.day, self.part, self.input)?;
+        match &self.outcome {
+            Outcome::Pass => write!(f, "ok"),
+            Outcome::Wrong(got) => write!(f, "expected {}, got {got}", self.expected),
+            Outcome::Error(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+fn run(day: u8, part: Part, file: &PathBuf, label: &str, expected: &str) -> Outcome {
+    let Some(entry) = find(day, part) else {
+        return Outcome::Error(format!("no solution for day {day} part {part}"));
+    };
+    let data = match input::read(file) {
+        Ok(data) => data,
+        Err(e) => return Outcome::Error(e.to_string()),
+    };
+    match entry.run(&data) {
+        Ok(got) if got == expected => Outcome::Pass,
+        Ok(got) => Outcome::Wrong(got),
+        Err(e) => Outcome::Error(e.report(label, &data).to_string()),
+    }
+}
+
+/// Runs every answer recorded for `day`, or `None` if it has no manifest.
+pub fn check(day: u8) -> Result<Option<Vec<Check>>, String> {
+    let Some(manifest) = load(day)? else {
+        return Ok(None);
+    };
+    let dir = path(day).with_file_name("");
+    let mut checks = vec![];
+    for e in &manifest.inputs {
+        let file = dir.join(&e.file);
+        let label = inputs::label(&file);
+        for part in [Part::A, Part::B] {
+            let Some(expected) = e.answer(part) else {
+                continue;
+            };
+            checks.push(Check {
+                day,
+                part,
+                outcome: run(day, part, &file, &label, expected),
+                input: label.clone(),
+                expected: expected.to_owned(),
+            });
+        }
+    }
+    Ok(Some(checks))
+}
@@ -0,0 +1,62 @@
+//! `advent verify`: runs every answer manifest and prints a pass/fail matrix.
+
+use advent::manifest::{self, Check, Outcome};
+use advent_core::Part;
+use std::process::ExitCode;
+
+fn cell(checks: &[&Check], part: Part) -> &'static str {
+    match checks.iter().find(|c| c.part == part).map(|c| &c.outcome) {
+        None => "-",
+        Some(Outcome::Pass) => "ok",
+        Some(Outcome::Wrong(_)) => "FAIL",
+        Some(Outcome::Error(_)) => "ERROR",
+    }
+}
+
+pub fn main(days: &[&str]) -> Option<ExitCode> {
+    let days = if days.is_empty() {
+        advent::days().collect()
+    } else {
+        days.iter()
+            .map(|d| d.parse().ok())
+            .collect::<Option<Vec<u8>>>()?
+    };
+
+    println!("{:>3}  {:<30} {:<5} b", "day", "input", "a");
+    let mut failed = vec![];
+    for day in days {
+        let checks = match manifest::check(day) {
+            Ok(Some(checks)) => checks,
+            Ok(None) => {
+                println!("{day:>3}  (no {})", manifest::path(day).display());
+                continue;
+            }
+            Err(e) => {
+                println!("{day:>3}  {e}");
+                failed.push(e);
+                continue;
+            }
+        };
+
+        let mut inputs: Vec<_> = checks.iter().map(|c| c.input.as_str()).collect();
+        inputs.dedup();
+        for input in inputs {
+            let row: Vec<_> = checks.iter().filter(|c| c.input == input).collect();
+            println!(
+                "{day:>3}  {input:<30} {:<5} {}",
+                cell(&row, Part::A),
+                cell(&row, Part::B)
+            );
+        }
+        failed.extend(checks.iter().filter(|c| !c.passed()).map(Check::to_string));
+    }
+
+    if failed.is_empty() {
+        return Some(ExitCode::SUCCESS);
+    }
+    println!();
+    for f in &failed {
+        println!("{f}");
+    }
+    Some(ExitCode::FAILURE)
+}
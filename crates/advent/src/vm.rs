@@ -0,0 +1,151 @@
+//! `advent vm`: runs a day 17 program on the 3-bit computer, optionally printing every step
+//! it takes and the registers at each breakpoint. Can also list the program instead, either
+//! disassembled or decompiled, or assemble a program into a puzzle input.
+
+use advent_core::{input, Day};
+use std::process::ExitCode;
+use vm::{asm, disasm, Machine, Stop, Word};
+
+/// Enough for any puzzle input many times over, so a program that loops forever stops in about
+/// a second instead of running until it's killed.
+const DEFAULT_BUDGET: u64 = 100_000_000;
+
+struct Options<'a> {
+    input: &'a str,
+    trace: bool,
+    disasm: bool,
+    decompile: bool,
+    asm: bool,
+    budget: u64,
+    breakpoints: Vec<usize>,
+}
+
+impl<'a> Options<'a> {
+    fn parse(args: &[&'a str]) -> Option<Self> {
+        let mut o = Options {
+            input: "-",
+            trace: false,
+            disasm: false,
+            decompile: false,
+            asm: false,
+            budget: DEFAULT_BUDGET,
+            breakpoints: vec![],
+        };
+        let mut args = args.iter();
+        while let Some(&arg) = args.next() {
+            match arg {
+                "--trace" => o.trace = true,
+                "--disasm" => o.disasm = true,
+                "--decompile" => o.decompile = true,
+                "--asm" => o.asm = true,
+                "--budget" => o.budget = args.next()?.parse().ok()?,
+                "--break" => o.breakpoints.push(args.next()?.parse().ok()?),
+                input => o.input = input,
+            }
+        }
+        Some(o)
+    }
+}
+
+fn registers<W: Word>(m: &Machine<W>) -> String {
+    let [a, b, c] = m.registers();
+    format!("A={a} B={b} C={c}")
+}
+
+pub fn main(args: &[&str]) -> Option<ExitCode> {
+    let o = Options::parse(args)?;
+    let label = if o.input == "-" { "<stdin>" } else { o.input };
+    let data = match o.input {
+        "-" => input::read_stdin(),
+        path => input::read(path),
+    };
+    let data = match data {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("{label}: {e}");
+            return Some(ExitCode::FAILURE);
+        }
+    };
+    if o.asm {
+        return Some(match asm::assemble(&data) {
+            Ok(program) => {
+                print!("{program}");
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{}", e.report(label, &data));
+                ExitCode::FAILURE
+            }
+        });
+    }
+    let (a, b, c, program) = match puzzle_17::Solution::parse(&data) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}", e.report(label, &data));
+            return Some(ExitCode::FAILURE);
+        }
+    };
+
+    if o.disasm {
+        for line in disasm::disassemble(&program) {
+            println!("{line}");
+        }
+    }
+    if o.decompile {
+        match disasm::decompile(&program) {
+            Some(code) => print!("{code}"),
+            None => {
+                eprintln!("{label}: only straight-line code or a single loop without bad opcodes can be decompiled");
+                return Some(ExitCode::FAILURE);
+            }
+        }
+    }
+    if o.disasm || o.decompile {
+        return Some(ExitCode::SUCCESS);
+    }
+
+    // only as wide as the registers need
+    Some(match [a, b, c].map(u64::try_from) {
+        [Ok(a), Ok(b), Ok(c)] => execute(Machine::new(program, [a, b, c]), &o, label),
+        _ => execute(Machine::new(program, [a, b, c]), &o, label),
+    })
+}
+
+fn execute<W: Word>(m: Machine<W>, o: &Options, label: &str) -> ExitCode {
+    let mut m = m.with_budget(o.budget);
+    if o.trace {
+        m = m.with_trace();
+    }
+    for &ip in &o.breakpoints {
+        m.add_breakpoint(ip);
+    }
+
+    let mut printed = 0;
+    let stop = loop {
+        let stop = m.run();
+        if o.trace {
+            for step in &m.trace()[printed..] {
+                println!("{step}");
+            }
+            printed = m.trace().len();
+        }
+        match stop {
+            Stop::Breakpoint(ip) => println!("break at {ip}: {}", registers(&m)),
+            stop => break stop,
+        }
+    };
+
+    let output: Vec<_> = m.output().iter().map(ToString::to_string).collect();
+    println!("{}", output.join(","));
+    match stop {
+        Stop::OutOfBudget => {
+            eprintln!("out of budget after {} steps: {}", m.steps(), registers(&m));
+            ExitCode::FAILURE
+        }
+        Stop::Fault(fault) => {
+            eprintln!("{label}: {fault}: {}", registers(&m));
+            ExitCode::FAILURE
+        }
+        _ => ExitCode::SUCCESS,
+    }
+}
@@ -0,0 +1,182 @@
+//! `advent warehouse`: moves day 15's robot around by hand, or replays the moves in its input.
+//! In a terminal, keys are read as they're pressed and every frame is drawn over the last one.
+//! `--script` reads keys from stdin and prints the frames one after another instead, for piping
+//! in a script.
+//!
+//! `--frames` runs the moves without drawing anything and writes every frame out instead, as
+//! text or ANSI colored text to stdout or `--out`, or as PPM or PGM images in the `--out`
+//! directory, to find which move a push goes wrong on.
+
+use advent_core::{input, Day};
+use puzzle_15::frames::{self, ImageFormat, Images};
+use puzzle_15::playground::{self, keys};
+use puzzle_15::Warehouse;
+use std::fs::File;
+use std::io::{self, BufWriter, IsTerminal, Read, Write};
+use std::process::{Command, ExitCode, Stdio};
+use std::time::Duration;
+
+struct Options<'a> {
+    input: Option<&'a str>,
+    height: usize,
+    width: usize,
+    replay: bool,
+    /// Moves per second in a replay, 0 for no waiting.
+    speed: u32,
+    script: bool,
+    frames: Option<Frames>,
+    out: Option<&'a str>,
+    /// Pixels to a tile in images.
+    pixels: usize,
+}
+
+#[derive(Clone, Copy)]
+enum Frames {
+    Text,
+    Ansi,
+    Image(ImageFormat),
+}
+
+impl<'a> Options<'a> {
+    fn parse(args: &[&'a str]) -> Option<Self> {
+        let mut o = Options {
+            input: None,
+            height: 1,
+            width: 1,
+            replay: false,
+            speed: 20,
+            script: false,
+            frames: None,
+            out: None,
+            pixels: 4,
+        };
+        let mut args = args.iter();
+        while let Some(&arg) = args.next() {
+            match arg {
+                "--wide" => o.width = 2,
+                "--scale" => {
+                    let (h, w) = args.next()?.split_once('x')?;
+                    o.height = h.parse().ok().filter(|&h| h > 0)?;
+                    o.width = w.parse().ok().filter(|&w| w > 0)?;
+                }
+                "--replay" => o.replay = true,
+                "--speed" => o.speed = args.next()?.parse().ok()?,
+                "--script" => o.script = true,
+                "--frames" => {
+                    o.frames = Some(match *args.next()? {
+                        "text" => Frames::Text,
+                        "ansi" => Frames::Ansi,
+                        "ppm" => Frames::Image(ImageFormat::Ppm),
+                        "pgm" => Frames::Image(ImageFormat::Pgm),
+                        _ => return None,
+                    })
+                }
+                "--out" => o.out = Some(args.next()?),
+                "--pixels" => o.pixels = args.next()?.parse().ok().filter(|&n| n > 0)?,
+                input if o.input.is_none() => o.input = Some(input),
+                _ => return None,
+            }
+        }
+        Some(o)
+    }
+}
+
+/// Switches the terminal to passing keys through as they're pressed without echoing them,
+/// and back again when dropped.
+struct Raw(String);
+
+impl Raw {
+    fn enable() -> Option<Self> {
+        let saved = Command::new("stty")
+            .arg("-g")
+            .stdin(Stdio::inherit())
+            .output()
+            .ok()?;
+        let saved = String::from_utf8(saved.stdout).ok()?.trim().to_owned();
+        stty(&["-icanon", "-echo", "min", "1"]);
+        Some(Raw(saved))
+    }
+}
+
+impl Drop for Raw {
+    fn drop(&mut self) {
+        stty(&[&self.0]);
+    }
+}
+
+fn stty(args: &[&str]) {
+    let _ = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .status();
+}
+
+/// Text frames to `out`, or stdout without it.
+fn log(out: Option<&str>) -> io::Result<Box<dyn Write>> {
+    Ok(match out {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(io::stdout().lock()),
+    })
+}
+
+fn export(
+    mut w: Warehouse,
+    moves: &[(isize, isize)],
+    o: &Options,
+    frames: Frames,
+) -> io::Result<()> {
+    match frames {
+        Frames::Text => w.run(moves, &mut frames::Text(log(o.out)?)),
+        Frames::Ansi => w.run(moves, &mut frames::Ansi(log(o.out)?)),
+        Frames::Image(format) => {
+            let dir = o.out.unwrap_or("frames");
+            w.run(moves, &mut Images::new(dir, format, o.pixels)?)
+        }
+    }
+}
+
+pub fn main(args: &[&str]) -> Option<ExitCode> {
+    let o = Options::parse(args)?;
+    // stdin is for the keys
+    let path = o.input?;
+    let data = match input::read(path) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("{path}: {e}");
+            return Some(ExitCode::FAILURE);
+        }
+    };
+    let (map, moves) = match puzzle_15::Solution::parse(&data) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}", e.report(path, &data));
+            return Some(ExitCode::FAILURE);
+        }
+    };
+    let w = Warehouse::new(&map, o.height, o.width);
+    let ansi = !o.script && io::stdout().is_terminal();
+    let mut out = io::stdout().lock();
+
+    let result = if let Some(frames) = o.frames {
+        export(w, &moves, &o, frames)
+    } else if o.replay {
+        let delay = match o.speed {
+            0 => Duration::ZERO,
+            n => Duration::from_secs(1) / n,
+        };
+        playground::replay(w, &moves, delay, &mut out, ansi).map(|_| ())
+    } else {
+        let _raw = (!o.script && io::stdin().is_terminal())
+            .then(Raw::enable)
+            .flatten();
+        let bytes = io::stdin().lock().bytes().map_while(Result::ok);
+        playground::play(w, keys(bytes), &mut out, ansi).map(|_| ())
+    };
+    match result {
+        Ok(()) => Some(ExitCode::SUCCESS),
+        Err(e) => {
+            eprintln!("{e}");
+            Some(ExitCode::FAILURE)
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Every day checked against the answers recorded in its `answers.toml`.
+
+fn check(day: u8) {
+    let checks = advent::manifest::check(day)
+        .unwrap()
+        .unwrap_or_else(|| panic!("day {day} has no answers.toml"));
+    assert!(!checks.is_empty(), "day {day} has no answers recorded");
+    let failed: Vec<_> = checks
+        .iter()
+        .filter(|c| !c.passed())
+        .map(ToString::to_string)
+        .collect();
+    assert!(failed.is_empty(), "\n{}", failed.join("\n"));
+}
+
+macro_rules! days {
+    ($($name:ident: $day:literal,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check($day);
+            }
+        )*
+
+        #[test]
+        fn every_day_is_tested() {
+            let tested = [$($day),*];
+            for day in advent::days() {
+                assert!(tested.contains(&day), "day {day} is missing from tests/answers.rs");
+            }
+        }
+    };
+}
+
+days! {
+    day_1: 1,
+    day_2: 2,
+    day_3: 3,
+    day_4: 4,
+    day_5: 5,
+    day_6: 6,
+    day_7: 7,
+    day_8: 8,
+    day_9: 9,
+    day_10: 10,
+    day_11: 11,
+    day_12: 12,
+    day_13: 13,
+    day_14: 14,
+    day_15: 15,
+    day_16: 16,
+    day_17: 17,
+    day_18: 18,
+    day_19: 19,
+    day_20: 20,
+}
@@ -0,0 +1,158 @@
+use nom::error::ErrorKind;
+use std::fmt::{self, Display};
+
+/// Something in an input file that a parser didn't expect, and where it was.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    col: usize,
+    found: String,
+    expected: String,
+}
+
+impl ParseError {
+    /// `found` has to be a slice of `input`, its line and column come from where it sits in
+    /// there. An empty slice means something was missing at that point.
+    pub fn new(input: &str, found: &str, expected: impl Into<String>) -> Self {
+        let offset = (found.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&o| o + found.len() <= input.len())
+            .expect("found is not part of the input");
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            col: before[line_start..].chars().count() + 1,
+            found: found.to_owned(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Something was missing at the very end of `input`.
+    pub fn eof(input: &str, expected: impl Into<String>) -> Self {
+        Self::new(input, &input[input.len()..], expected)
+    }
+
+    /// Converts the error from a nom parser that was run on `input`.
+    pub fn nom(input: &str, e: nom::Err<nom::error::Error<&str>>) -> Self {
+        match e {
+            nom::Err::Incomplete(_) => Self::eof(input, "more input"),
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                // point at the next character, or the line break if that's what's there
+                let len = e.input.chars().next().map_or(0, char::len_utf8);
+                let len = if e.input.starts_with("\r\n") { 2 } else { len };
+                Self::new(input, &e.input[..len], describe(e.code))
+            }
+        }
+    }
+
+    /// 1-based.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// 1-based, counted in characters.
+    pub fn col(&self) -> usize {
+        self.col
+    }
+
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// The error followed by the offending line of `input`, with a caret under the bad text.
+    pub fn report<'a>(&'a self, path: &'a str, input: &'a str) -> impl Display + 'a {
+        Report {
+            err: self,
+            path,
+            input,
+        }
+    }
+
+    fn message(&self) -> String {
+        let found = match self.found.as_str() {
+            "" => "end of input".to_owned(),
+            "\n" | "\r\n" => "end of line".to_owned(),
+            s => format!("`{}`", s.escape_debug()),
+        };
+        format!("expected {}, found {found}", self.expected)
+    }
+}
+
+fn describe(code: ErrorKind) -> String {
+    match code {
+        ErrorKind::Digit => "a number",
+        ErrorKind::Char => "a different character",
+        ErrorKind::Tag => "a different keyword",
+        ErrorKind::CrLf => "a line break",
+        ErrorKind::Eof => "end of input",
+        ErrorKind::Space => "a space",
+        ErrorKind::Alpha => "a letter",
+        _ => return code.description().to_lowercase(),
+    }
+    .to_owned()
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.col,
+            self.message()
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+struct Report<'a> {
+    err: &'a ParseError,
+    path: &'a str,
+    input: &'a str,
+}
+
+impl Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ParseError {
+            line, col, found, ..
+        } = self.err;
+        let src = self.input.lines().nth(line - 1).unwrap_or("");
+        let gutter = " ".repeat(line.to_string().len());
+        let width = found.lines().next().map_or(0, |l| l.chars().count()).max(1);
+
+        writeln!(f, "error: {}", self.err.message())?;
+        writeln!(f, "{gutter}--> {}:{line}:{col}", self.path)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line} | {src}")?;
+        write!(f, "{gutter} | {}{}", " ".repeat(col - 1), "^".repeat(width))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn position() {
+        let input = "12 34\n56 x8\n";
+        let e = ParseError::new(input, &input[9..10], "a number");
+        assert_eq!((e.line(), e.col()), (2, 4));
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 4: expected a number, found `x`"
+        );
+        assert_eq!(
+            e.report("in.txt", input).to_string(),
+            "error: expected a number, found `x`\n --> in.txt:2:4\n  |\n2 | 56 x8\n  |    ^"
+        );
+
+        let e = ParseError::eof(input, "more lines");
+        assert_eq!((e.line(), e.col()), (3, 1));
+    }
+}
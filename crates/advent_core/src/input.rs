@@ -0,0 +1,75 @@
+//! Loading puzzle inputs. Everything handed to [`Day::parse`](crate::Day::parse) has gone
+//! through [`normalize`], so parsers only ever see `\n` line endings, no byte order mark, and
+//! exactly one newline at the end.
+
+use std::borrow::Cow;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// Reads `path` and [`normalize`]s it.
+pub fn read(path: impl AsRef<Path>) -> io::Result<String> {
+    let s = std::fs::read_to_string(path)?;
+    Ok(normalize(&s).into_owned())
+}
+
+/// Reads everything piped into stdin and [`normalize`]s it.
+pub fn read_stdin() -> io::Result<String> {
+    let mut s = String::new();
+    io::stdin().lock().read_to_string(&mut s)?;
+    Ok(normalize(&s).into_owned())
+}
+
+/// Strips a UTF-8 byte order mark, turns `\r\n` into `\n`, and drops trailing blank lines so
+/// the text ends in a single `\n` (or is empty). Borrows when there's nothing to change.
+pub fn normalize(s: &str) -> Cow<'_, str> {
+    let s = s.strip_prefix('\u{feff}').unwrap_or(s);
+    let body = s.trim_end_matches(['\r', '\n']);
+    if body.is_empty() {
+        return Cow::Borrowed("");
+    }
+    // a lone `\r` at the end is the same length as the `\n` it should be
+    if !body.contains('\r') && &s[body.len()..] == "\n" {
+        return Cow::Borrowed(s);
+    }
+    let mut out = body.replace("\r\n", "\n");
+    out.push('\n');
+    Cow::Owned(out)
+}
+
+/// Splits normalized input into its blank-line-separated sections. Each section keeps the
+/// newline ending its last line, and is a slice of `s` so errors inside it still point at the
+/// right line of the file.
+pub fn sections(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = s.trim_start_matches('\n');
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let end = rest.find("\n\n").map_or(rest.len(), |i| i + 1);
+        let (section, tail) = rest.split_at(end);
+        rest = tail.trim_start_matches('\n');
+        Some(section)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn line_endings() {
+        assert!(matches!(normalize("a\nb\n"), Cow::Borrowed("a\nb\n")));
+        assert_eq!(normalize("\u{feff}a\r\nb"), "a\nb\n");
+        assert_eq!(normalize("a\n\nb\r\n\r\n\r\n"), "a\n\nb\n");
+        assert_eq!(normalize("\n\n"), "");
+        assert!(matches!(normalize("a\r"), Cow::Owned(s) if s == "a\n"));
+        assert_eq!(normalize("a\n\r"), "a\n");
+    }
+
+    #[test]
+    fn split_sections() {
+        let s = normalize("a\nb\r\n\r\nc\r\n\r\n\r\nd\r\n");
+        assert_eq!(sections(&s).collect::<Vec<_>>(), ["a\nb\n", "c\n", "d\n"]);
+        assert_eq!(sections("").count(), 0);
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
+
+mod error;
+pub mod input;
+
+pub use error::ParseError;
+
+/// Parsing shared by both parts of a day. `parse` is always given [`input::normalize`]d text.
+pub trait Day {
+    type Input;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError>;
+}
+
+pub trait PartA: Day {
+    type A: Answer;
+
+    fn part_a(input: &Self::Input) -> Self::A;
+}
+
+pub trait PartB: Day {
+    type B: Answer;
+
+    fn part_b(input: &Self::Input) -> Self::B;
+}
+
+/// What a part hands back. Anything besides the answer itself that's worth reporting, like
+/// where it was found, goes in [`extra`](Answer::extra).
+pub trait Answer: Display {
+    /// Named values shown next to the answer in machine-readable or verbose output.
+    fn extra(&self) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+}
+
+macro_rules! plain_answers {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {})*
+    };
+}
+
+plain_answers!(u32, u64, u128, usize, i32, i64, i128, isize, String);
+
+/// Both answers for a day from a single parse.
+pub fn both<D: PartA + PartB>(s: &str) -> Result<(D::A, D::B), ParseError> {
+    let input = D::parse(&input::normalize(s))?;
+    Ok((D::part_a(&input), D::part_b(&input)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "a" | "A" => Some(Part::A),
+            "b" | "B" => Some(Part::B),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::A => f.write_str("a"),
+            Part::B => f.write_str("b"),
+        }
+    }
+}
+
+/// An answer along with how long each phase took to produce it. Normalizing the input isn't
+/// counted towards either.
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub answer: String,
+    pub extra: Vec<(&'static str, String)>,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// One part of a day with its type erased, so the runner can keep every day in one table.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub day: u8,
+    pub part: Part,
+    run: fn(&str) -> Result<Timed, ParseError>,
+}
+
+impl Entry {
+    pub const fn a<D: PartA>(day: u8) -> Self {
+        Self {
+            day,
+            part: Part::A,
+            run: |s| timed::<D, _>(s, D::part_a),
+        }
+    }
+
+    pub const fn b<D: PartB>(day: u8) -> Self {
+        Self {
+            day,
+            part: Part::B,
+            run: |s| timed::<D, _>(s, D::part_b),
+        }
+    }
+
+    pub fn run(&self, s: &str) -> Result<String, ParseError> {
+        self.timed(s).map(|t| t.answer)
+    }
+
+    pub fn timed(&self, s: &str) -> Result<Timed, ParseError> {
+        (self.run)(s)
+    }
+}
+
+fn parse<D: Day>(s: &str) -> Result<(D::Input, Duration), ParseError> {
+    let s = input::normalize(s);
+    let start = Instant::now();
+    let input = D::parse(&s)?;
+    Ok((input, start.elapsed()))
+}
+
+fn solve<D: Day, T: Answer>(input: &D::Input, parse: Duration, f: fn(&D::Input) -> T) -> Timed {
+    let start = Instant::now();
+    let output = f(input);
+    let solve = start.elapsed();
+    Timed {
+        answer: output.to_string(),
+        extra: output.extra(),
+        parse,
+        solve,
+    }
+}
+
+fn timed<D: Day, T: Answer>(s: &str, f: fn(&D::Input) -> T) -> Result<Timed, ParseError> {
+    let (input, parse) = parse::<D>(s)?;
+    Ok(solve::<D, T>(&input, parse, f))
+}
+
+/// [`both`] with its type erased, for the days that have both parts. Each part is timed on its
+/// own, and both report the one parse they shared.
+#[derive(Clone, Copy)]
+pub struct Both {
+    pub day: u8,
+    run: fn(&str) -> Result<[Timed; 2], ParseError>,
+}
+
+impl Both {
+    pub const fn new<D: PartA + PartB>(day: u8) -> Self {
+        Self {
+            day,
+            run: |s| {
+                let (input, parse) = parse::<D>(s)?;
+                Ok([
+                    solve::<D, _>(&input, parse, D::part_a),
+                    solve::<D, _>(&input, parse, D::part_b),
+                ])
+            },
+        }
+    }
+
+    pub fn run(&self, s: &str) -> Result<[Timed; 2], ParseError> {
+        (self.run)(s)
+    }
+}
@@ -0,0 +1,294 @@
+use advent_core::ParseError;
+use std::fmt::{self, Display, Write};
+use std::ops::{Index, IndexMut};
+
+/// `(row, column)`, matching how every day already indexes `Vec<Vec<T>>`.
+pub type Pos = (usize, usize);
+
+/// North, east, south, west.
+pub const DIRS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Clockwise from north.
+pub const DIRS8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(height: usize, width: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; height * width],
+            height,
+            width,
+        }
+    }
+
+    /// Panics if the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(height * width);
+        for r in rows {
+            assert_eq!(r.len(), width, "ragged grid");
+            cells.extend(r);
+        }
+        Self {
+            cells,
+            height,
+            width,
+        }
+    }
+
+    /// Builds a grid from a character map, one line per row. Blank lines are skipped so trailing
+    /// newlines don't produce empty rows.
+    pub fn parse(s: &str, mut f: impl FnMut(char) -> T) -> Self {
+        Self::from_rows(
+            s.lines()
+                .filter(|l| !l.is_empty())
+                .map(|l| l.chars().map(&mut f).collect())
+                .collect(),
+        )
+    }
+
+    /// [`Grid::parse`] for maps that can contain characters `f` doesn't know, which it signals
+    /// by returning `None`. The error points at the first such character and says `expected`.
+    pub fn try_parse(
+        s: &str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut rows: Vec<Vec<T>> = vec![];
+        for l in s.lines().filter(|l| !l.is_empty()) {
+            let row = l
+                .char_indices()
+                .map(|(j, c)| {
+                    f(c).ok_or_else(|| ParseError::new(s, &l[j..j + c.len_utf8()], expected))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(width) = rows.first().map(Vec::len).filter(|&w| w != row.len()) {
+                return Err(ParseError::new(s, l, format!("a row {width} wide")));
+            }
+            rows.push(row);
+        }
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, (i, j): Pos) -> bool {
+        i < self.height && j < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn swap(&mut self, a: Pos, b: Pos) {
+        assert!(self.contains(a) && self.contains(b));
+        self.cells
+            .swap(a.0 * self.width + a.1, b.0 * self.width + b.1);
+    }
+
+    /// `pos` moved by `(di, dj)`, or `None` if that leaves the grid.
+    pub fn offset(&self, (i, j): Pos, (di, dj): (isize, isize)) -> Option<Pos> {
+        let p = (i.checked_add_signed(di)?, j.checked_add_signed(dj)?);
+        self.contains(p).then_some(p)
+    }
+
+    /// In-bounds orthogonal neighbors, in [`DIRS4`] order.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS4.into_iter().filter_map(move |d| self.offset(pos, d))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbors, in [`DIRS8`] order.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS8.into_iter().filter_map(move |d| self.offset(pos, d))
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks_exact panics on a zero chunk size, which an empty grid would otherwise hit
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, j: usize) -> impl Iterator<Item = &T> {
+        assert!(j < self.width);
+        self.cells.iter().skip(j).step_by(self.width)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| (i, j)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    /// First cell (in row-major order) matching `f`.
+    pub fn position(&self, mut f: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, t)| f(t)).map(|(p, _)| p)
+    }
+
+    /// First cell (in row-major order) equal to `t`, e.g. an `S` or `@` marker.
+    pub fn find(&self, t: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.position(|c| c == t)
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(&mut f).collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+
+    /// A copy surrounded by a one cell border of `border`, so neighbor lookups near the edge
+    /// never need bounds checks. Positions in the new grid are shifted by `(1, 1)`.
+    pub fn padded(&self, border: T) -> Self
+    where
+        T: Clone,
+    {
+        let mut g = Grid::new(self.height + 2, self.width + 2, border);
+        for ((i, j), t) in self.iter() {
+            g[(i + 1, j + 1)] = t.clone();
+        }
+        g
+    }
+
+    /// Renders each cell with `f`, for overlaying extra information (paths, visited cells...)
+    /// on top of the map.
+    pub fn display_with<'a, F>(&'a self, f: F) -> impl Display + 'a
+    where
+        F: Fn(Pos, &T) -> char + 'a,
+    {
+        DisplayWith { grid: self, f }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{pos:?} out of bounds");
+        &self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{pos:?} out of bounds");
+        &mut self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+struct DisplayWith<'a, T, F> {
+    grid: &'a Grid<T>,
+    f: F,
+}
+
+impl<T, F: Fn(Pos, &T) -> char> Display for DisplayWith<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, r) in self.grid.rows().enumerate() {
+            for (j, t) in r.iter().enumerate() {
+                f.write_char((self.f)((i, j), t))?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Copy + Into<char>> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display_with(|_, &t| t.into()).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAP: &str = "#.S\n.#.\nE..\n";
+
+    #[test]
+    fn parse_and_find() {
+        let g = Grid::parse(MAP, |c| c);
+        assert_eq!((g.height(), g.width()), (3, 3));
+        assert_eq!(g.find(&'S'), Some((0, 2)));
+        assert_eq!(g.find(&'E'), Some((2, 0)));
+        assert_eq!(g.find(&'X'), None);
+        assert_eq!(g.to_string(), MAP);
+
+        let e = Grid::try_parse("#.\n.x\n", "`#` or `.`", |c| "#.".contains(c).then_some(c));
+        let e = e.unwrap_err();
+        assert_eq!((e.line(), e.col(), e.found()), (2, 2, "x"));
+        assert!(Grid::try_parse("#.\n.\n", "", Some).is_err());
+    }
+
+    #[test]
+    fn neighbors() {
+        let g = Grid::new(3, 3, 0);
+        assert_eq!(g.neighbors4((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+        assert_eq!(g.neighbors4((1, 1)).count(), 4);
+        assert_eq!(g.neighbors8((1, 1)).count(), 8);
+        assert_eq!(
+            g.neighbors8((2, 2)).collect::<Vec<_>>(),
+            [(1, 2), (2, 1), (1, 1)]
+        );
+        assert_eq!(g.offset((1, 1), (-1, -1)), Some((0, 0)));
+        assert_eq!(g.offset((1, 1), (-2, 0)), None);
+        assert_eq!(g.offset((1, 1), (0, 2)), None);
+    }
+
+    #[test]
+    fn rows_columns_padding() {
+        let g = Grid::parse("ab\ncd\n", |c| c);
+        assert_eq!(g.rows().collect::<Vec<_>>(), [&['a', 'b'], &['c', 'd']]);
+        assert_eq!(g.column(1).collect::<String>(), "bd");
+        let p = g.padded('#');
+        assert_eq!(p.to_string(), "####\n#ab#\n#cd#\n####\n");
+        assert_eq!(p[(1, 1)], g[(0, 0)]);
+    }
+}
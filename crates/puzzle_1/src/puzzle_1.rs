@@ -0,0 +1,81 @@
+use advent_core::{Day, ParseError, PartA, PartB};
+use std::collections::{hash_map::Entry, HashMap};
+
+fn calc_distance(left: &mut [i64], right: &mut [i64]) -> u64 {
+    left.sort();
+    right.sort();
+    left.iter()
+        .zip(right.iter())
+        .fold(0u64, |total, (l, r)| total + l.abs_diff(*r))
+}
+
+fn calc_similarity(left: &[i64], right: &HashMap<i64, u64>) -> u64 {
+    left.iter().fold(0, |total, &n| {
+        total + (n as u64 * right.get(&n).copied().unwrap_or(0))
+    })
+}
+
+fn count(right: &[i64]) -> HashMap<i64, u64> {
+    let mut counts = HashMap::new();
+    for &n in right {
+        let v = match counts.entry(n) {
+            Entry::Vacant(e) => e.insert(0u64),
+            Entry::Occupied(e) => e.into_mut(),
+        };
+        *v += 1;
+    }
+    counts
+}
+
+fn parse_file(s: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let ints = s
+        .split_ascii_whitespace()
+        .map(|t| {
+            t.parse::<i64>()
+                .map_err(|_| ParseError::new(s, t, "a number"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if ints.len() % 2 != 0 {
+        return Err(ParseError::eof(s, "a number for the right list"));
+    }
+    Ok(ints.chunks_exact(2).map(|s| (s[0], s[1])).unzip())
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = (Vec<i64>, Vec<i64>);
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = u64;
+
+    fn part_a((left, right): &Self::Input) -> Self::A {
+        calc_distance(&mut left.clone(), &mut right.clone())
+    }
+}
+
+impl PartB for Solution {
+    type B = u64;
+
+    fn part_b((left, right): &Self::Input) -> Self::B {
+        calc_similarity(left, &count(right))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let mut left = vec![3, 4, 2, 1, 3, 3];
+        let mut right = vec![4, 3, 5, 3, 9, 3];
+        assert_eq!(calc_similarity(&left, &count(&right)), 31);
+        assert_eq!(calc_distance(&mut left, &mut right), 11);
+    }
+}
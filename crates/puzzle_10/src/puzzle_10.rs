@@ -0,0 +1,86 @@
+use advent_core::{Day, ParseError, PartA, PartB};
+use grid::{Grid, Pos};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::collections::BinaryHeap;
+
+fn parse_file(s: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::try_parse(s, "a height from 0 to 9", |h| h.to_digit(10))
+}
+
+fn score(data: &Grid<u32>, start: Pos) -> u64 {
+    let mut total = 0;
+    let mut heap = BinaryHeap::new();
+    let mut seen = Grid::new(data.height(), data.width(), false);
+    seen[start] = true;
+    heap.push((data[start], start));
+
+    while let Some((height, curr)) = heap.pop() {
+        if height == 9 {
+            total += 1;
+            continue;
+        }
+        for p in data.neighbors4(curr) {
+            if !seen[p] && data[p] == height + 1 {
+                seen[p] = true;
+                heap.push((data[p], p));
+            }
+        }
+    }
+
+    total
+}
+
+fn rating(data: &Grid<u32>, start: Pos) -> u64 {
+    let mut total = 0;
+    let mut heap = BinaryHeap::new();
+    heap.push((data[start], start));
+
+    while let Some((height, curr)) = heap.pop() {
+        if height == 9 {
+            total += 1;
+            continue;
+        }
+        for p in data.neighbors4(curr) {
+            if data[p] == height + 1 {
+                heap.push((data[p], p));
+            }
+        }
+    }
+
+    total
+}
+
+fn solve(data: &Grid<u32>, trailhead: fn(&Grid<u32>, Pos) -> u64) -> u64 {
+    let starts: Vec<_> = data
+        .iter()
+        .filter(|(_, &h)| h == 0)
+        .map(|(p, _)| p)
+        .collect();
+    starts.into_par_iter().map(|p| trailhead(data, p)).sum()
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Grid<u32>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = u64;
+
+    fn part_a(v: &Self::Input) -> Self::A {
+        solve(v, score)
+    }
+}
+
+impl PartB for Solution {
+    type B = u64;
+
+    fn part_b(v: &Self::Input) -> Self::B {
+        solve(v, rating)
+    }
+}
@@ -0,0 +1,106 @@
+use advent_core::{Day, ParseError, PartA, PartB};
+use either::Either;
+use hashbrown::HashMap;
+
+fn parse_file(s: &str) -> Result<Vec<u64>, ParseError> {
+    s.lines()
+        .flat_map(|l| l.split(' '))
+        .map(|t| t.parse().map_err(|_| ParseError::new(s, t, "a number")))
+        .collect()
+}
+
+fn blink(data: &[u64]) -> Vec<u64> {
+    data.iter()
+        .flat_map(|&d| {
+            if d == 0 {
+                Either::Left(std::iter::once(1))
+            } else {
+                let n = (d as f64).log10().trunc() as u32 + 1;
+                if n & 1 == 1 {
+                    Either::Left(std::iter::once(d * 2024))
+                } else {
+                    let base = 10u64.pow(n / 2);
+                    let r = d / base;
+                    let m = d % base;
+                    Either::Right(std::iter::once(r).chain(std::iter::once(m)))
+                }
+            }
+        })
+        .collect()
+}
+
+fn count_stones(mut data: Vec<u64>, blinks: usize) -> usize {
+    for _ in 0..blinks {
+        data = blink(&data);
+    }
+    data.len()
+}
+
+fn next_n(d: u64) -> (u64, Option<u64>) {
+    if d == 0 {
+        (1, None)
+    } else {
+        let n = (d as f64).log10().trunc() as u32 + 1;
+        if n & 1 == 1 {
+            (d * 2024, None)
+        } else {
+            let base = 10u64.pow(n / 2);
+            let r = d / base;
+            let m = d % base;
+            (r, Some(m))
+        }
+    }
+}
+
+fn get_n_at_iter(d: u64, base: u64, m: &mut HashMap<(u64, u64), usize>) -> usize {
+    if base == 0 {
+        return 1;
+    }
+
+    if let Some(&res) = m.get(&(d, base)) {
+        return res;
+    }
+
+    let (a, b) = next_n(d);
+    let mut res = get_n_at_iter(a, base - 1, m);
+    if let Some(b) = b {
+        res += get_n_at_iter(b, base - 1, m);
+    }
+    m.insert((d, base), res);
+    res
+}
+
+fn count_stones_memo(data: &[u64], blinks: u64) -> usize {
+    let mut m: HashMap<(u64, u64), usize> = HashMap::new();
+    let mut total = 0;
+    for &d in data {
+        total += get_n_at_iter(d, blinks, &mut m);
+    }
+    total
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Vec<u64>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = usize;
+
+    fn part_a(v: &Self::Input) -> Self::A {
+        count_stones(v.clone(), 25)
+    }
+}
+
+impl PartB for Solution {
+    type B = usize;
+
+    fn part_b(v: &Self::Input) -> Self::B {
+        count_stones_memo(v, 75)
+    }
+}
@@ -0,0 +1,172 @@
+use advent_core::{Day, ParseError, PartA, PartB};
+use grid::{Grid, Pos, DIRS4};
+
+#[derive(Clone, Copy)]
+pub struct Plot {
+    c: char,
+    seen: bool,
+}
+
+fn in_line(a: Pos, b: Pos) -> bool {
+    a.0 == b.0 || a.1 == b.1
+}
+
+fn parse_file(s: &str) -> Result<Grid<Plot>, ParseError> {
+    Grid::try_parse(s, "a plant letter", |c| {
+        c.is_ascii_alphabetic().then_some(Plot { c, seen: false })
+    })
+}
+
+fn perimeter_cost(data: &mut Grid<Plot>, start: Pos) -> u64 {
+    let mut area = 0;
+    let mut per = 0;
+    let mut candidates = vec![start];
+
+    while let Some(curr) = candidates.pop() {
+        let c = data[curr].c;
+        area += 1;
+        per += 4;
+        for dir in DIRS4 {
+            let Some(pos) = data.offset(curr, dir) else {
+                continue;
+            };
+            let p = &mut data[pos];
+            if p.c == c {
+                per -= 1;
+                if !p.seen {
+                    p.seen = true;
+                    candidates.push(pos);
+                }
+            }
+        }
+    }
+
+    area * per
+}
+
+fn count_corners(data: &Grid<Plot>, curr: Pos, nbrs: &[Pos]) -> u64 {
+    let c = data[curr].c;
+    match nbrs.len() {
+        0 => 4,
+        1 => 2,
+        2 => {
+            if in_line(nbrs[0], nbrs[1]) {
+                return 0;
+            }
+
+            let x = if curr.0 == nbrs[0].0 {
+                nbrs[1].0
+            } else {
+                nbrs[0].0
+            };
+
+            let y = if curr.1 == nbrs[0].1 {
+                nbrs[1].1
+            } else {
+                nbrs[0].1
+            };
+
+            if data[(x, y)].c == c {
+                1
+            } else {
+                2
+            }
+        }
+        3 => {
+            let idx: usize = if in_line(nbrs[0], nbrs[1]) {
+                2
+            } else if in_line(nbrs[0], nbrs[2]) {
+                1
+            } else {
+                0
+            };
+
+            let n = nbrs[idx];
+            let mut ret = 0;
+            if n.0 == curr.0 {
+                if data[(n.0 + 1, n.1)].c != c {
+                    ret += 1;
+                }
+                if data[(n.0 - 1, n.1)].c != c {
+                    ret += 1;
+                }
+            } else {
+                if data[(n.0, n.1 + 1)].c != c {
+                    ret += 1;
+                }
+                if data[(n.0, n.1 - 1)].c != c {
+                    ret += 1;
+                }
+            }
+            ret
+        }
+        // every orthogonal neighbor matches, so only the diagonals can differ
+        4 => data.neighbors8(curr).filter(|&p| data[p].c != c).count() as u64,
+        _ => unreachable!(),
+    }
+}
+
+fn sides_cost(data: &mut Grid<Plot>, start: Pos) -> u64 {
+    let mut area = 0;
+    let mut sides = 0;
+    let mut candidates = vec![start];
+
+    while let Some(curr) = candidates.pop() {
+        let c = data[curr].c;
+        area += 1;
+        for dir in DIRS4 {
+            let Some(pos) = data.offset(curr, dir) else {
+                continue;
+            };
+            let p = &mut data[pos];
+            if p.c == c && !p.seen {
+                p.seen = true;
+                candidates.push(pos);
+            }
+        }
+
+        let nbrs: Vec<_> = data.neighbors4(curr).filter(|&p| data[p].c == c).collect();
+
+        sides += count_corners(data, curr, &nbrs);
+    }
+
+    area * sides
+}
+
+fn solve(data: &Grid<Plot>, plot_cost: fn(&mut Grid<Plot>, Pos) -> u64) -> u64 {
+    let mut data = data.clone();
+    let mut cost: u64 = 0;
+    for pos in data.positions() {
+        if !data[pos].seen {
+            data[pos].seen = true;
+            cost += plot_cost(&mut data, pos);
+        }
+    }
+    cost
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Grid<Plot>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = u64;
+
+    fn part_a(v: &Self::Input) -> Self::A {
+        solve(v, perimeter_cost)
+    }
+}
+
+impl PartB for Solution {
+    type B = u64;
+
+    fn part_b(v: &Self::Input) -> Self::B {
+        solve(v, sides_cost)
+    }
+}
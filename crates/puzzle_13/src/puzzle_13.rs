@@ -0,0 +1,224 @@
+use advent_core::{Day, ParseError, PartA, PartB};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, line_ending},
+    combinator::eof,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, terminated, tuple},
+    IResult,
+};
+
+const PART_B_OFFSET: i128 = 10000000000000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Vec2 {
+    x: i128,
+    y: i128,
+}
+
+impl Vec2 {
+    /// The z of the cross product, 0 when the two point the same way (or either is zero).
+    fn cross(self, o: Vec2) -> Option<i128> {
+        self.x
+            .checked_mul(o.y)?
+            .checked_sub(self.y.checked_mul(o.x)?)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Machine {
+    a: Vec2,
+    b: Vec2,
+    prize: Vec2,
+}
+
+type RowData = Machine;
+
+fn parse_xy<'a>(x: &'a str, y: &'a str) -> impl FnMut(&'a str) -> IResult<&'a str, Vec2> {
+    move |s| {
+        let (s, (x, y)) = separated_pair(
+            preceded(tag(x), complete::u64),
+            tag(", "),
+            preceded(tag(y), complete::u64),
+        )(s)?;
+        Ok((
+            s,
+            Vec2 {
+                x: x.into(),
+                y: y.into(),
+            },
+        ))
+    }
+}
+
+fn parse_machine(s: &str) -> IResult<&str, RowData> {
+    let (s, (a, b, prize)) = tuple((
+        terminated(
+            preceded(tag("Button A: "), parse_xy("X+", "Y+")),
+            line_ending,
+        ),
+        terminated(
+            preceded(tag("Button B: "), parse_xy("X+", "Y+")),
+            line_ending,
+        ),
+        terminated(preceded(tag("Prize: "), parse_xy("X=", "Y=")), line_ending),
+    ))(s)?;
+    Ok((s, Machine { a, b, prize }))
+}
+
+fn parse_file(s: &str) -> IResult<&str, Vec<RowData>> {
+    terminated(separated_list1(line_ending, parse_machine), eof)(s)
+}
+
+fn in_limit(presses: i128, limit: Option<i128>) -> bool {
+    presses >= 0 && limit.is_none_or(|l| presses <= l)
+}
+
+fn floor_div(n: i128, d: i128) -> i128 {
+    n.div_euclid(d)
+}
+
+fn ceil_div(n: i128, d: i128) -> i128 {
+    -(-n).div_euclid(d)
+}
+
+/// `(g, x, y)` with `u * x + v * y == g`.
+fn ext_gcd(u: i128, v: i128) -> (i128, i128, i128) {
+    if v == 0 {
+        (u, 1, 0)
+    } else {
+        let (g, x, y) = ext_gcd(v, u % v);
+        (g, y, x - u / v * y)
+    }
+}
+
+/// The cheapest presses `(a, b)` with `a * u + b * v == w`, for when both buttons and the prize
+/// are on one line. Every solution is one of `a0 + k * v/g, b0 - k * u/g`, and the cost is
+/// linear in `k`, so the cheapest is at one end of the range of `k` that keeps both counts in
+/// bounds.
+fn solve_line(u: i128, v: i128, w: i128, limit: Option<i128>) -> Option<(i128, i128)> {
+    let (a, b) = match (u, v) {
+        (0, 0) => (w == 0).then_some((0, 0))?,
+        (0, v) => (0, (w % v == 0).then_some(w / v)?),
+        (u, 0) => ((w % u == 0).then_some(w / u)?, 0),
+        (u, v) => {
+            let (g, x, y) = ext_gcd(u, v);
+            if w % g != 0 {
+                return None;
+            }
+            let (a0, b0) = (x.checked_mul(w / g)?, y.checked_mul(w / g)?);
+            let (sv, su) = (v / g, u / g);
+            let mut lo = ceil_div(-a0, sv);
+            let mut hi = floor_div(b0, su);
+            if let Some(l) = limit {
+                lo = lo.max(ceil_div(b0 - l, su));
+                hi = hi.min(floor_div(l - a0, sv));
+            }
+            if lo > hi {
+                return None;
+            }
+            // each step of k costs 3 * v/g more for A and saves u/g on B
+            let k = if 3 * sv > su { lo } else { hi };
+            (
+                a0.checked_add(k.checked_mul(sv)?)?,
+                b0.checked_sub(k.checked_mul(su)?)?,
+            )
+        }
+    };
+    (in_limit(a, limit) && in_limit(b, limit)).then_some((a, b))
+}
+
+/// The fewest tokens that win the prize, pushing A for 3 and B for 1, or `None` if it can't be
+/// won. Cramer's rule gives the only answer when the buttons move in different directions.
+fn tokens(m: &Machine, offset: i128, limit: Option<i128>) -> Option<i128> {
+    let p = Vec2 {
+        x: m.prize.x.checked_add(offset)?,
+        y: m.prize.y.checked_add(offset)?,
+    };
+    let det = m.a.cross(m.b)?;
+    let (a, b) = if det != 0 {
+        let (na, nb) = (p.cross(m.b)?, m.a.cross(p)?);
+        if na % det != 0 || nb % det != 0 {
+            return None;
+        }
+        let (a, b) = (na / det, nb / det);
+        if !in_limit(a, limit) || !in_limit(b, limit) {
+            return None;
+        }
+        (a, b)
+    } else {
+        if m.a.cross(p)? != 0 || m.b.cross(p)? != 0 {
+            return None;
+        }
+        // everything's on one line, so matching x matches y too unless that line is x = 0
+        if m.a.x != 0 || m.b.x != 0 || p.x != 0 {
+            solve_line(m.a.x, m.b.x, p.x, limit)?
+        } else {
+            solve_line(m.a.y, m.b.y, p.y, limit)?
+        }
+    };
+    a.checked_mul(3)?.checked_add(b)
+}
+
+fn total_tokens(v: &[RowData], offset: i128, limit: Option<i128>) -> i128 {
+    v.iter().filter_map(|m| tokens(m, offset, limit)).sum()
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Vec<RowData>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+            .map(|(_, v)| v)
+            .map_err(|e| ParseError::nom(s, e))
+    }
+}
+
+impl PartA for Solution {
+    type A = i128;
+
+    fn part_a(v: &Self::Input) -> Self::A {
+        // no button needs pushing more than 100 times
+        total_tokens(v, 0, Some(100))
+    }
+}
+
+impl PartB for Solution {
+    type B = i128;
+
+    fn part_b(v: &Self::Input) -> Self::B {
+        total_tokens(v, PART_B_OFFSET, None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn machine(a: (i128, i128), b: (i128, i128), prize: (i128, i128)) -> Machine {
+        let v = |(x, y)| Vec2 { x, y };
+        Machine {
+            a: v(a),
+            b: v(b),
+            prize: v(prize),
+        }
+    }
+
+    #[test]
+    fn collinear() {
+        // B is cheaper per step, so only B
+        assert_eq!(tokens(&machine((2, 2), (1, 1), (5, 5)), 0, None), Some(5));
+        // A goes 4 for 3 tokens, so as much A as fits
+        assert_eq!(tokens(&machine((4, 4), (1, 1), (9, 9)), 0, None), Some(7));
+        // B is cheaper again, but capped at 100 presses
+        assert_eq!(
+            tokens(&machine((3, 6), (2, 4), (500, 1000)), 0, Some(100)),
+            Some(3 * 100 + 100)
+        );
+        assert_eq!(tokens(&machine((2, 2), (4, 4), (5, 5)), 0, None), None);
+        assert_eq!(tokens(&machine((1, 1), (2, 2), (3, 4)), 0, None), None);
+        assert_eq!(tokens(&machine((0, 3), (0, 1), (0, 7)), 0, None), Some(7));
+    }
+}
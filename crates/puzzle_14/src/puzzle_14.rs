@@ -0,0 +1,163 @@
+use advent_core::{Day, ParseError, PartA, PartB};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, char, line_ending},
+    combinator::eof,
+    multi::many_till,
+    sequence::{preceded, separated_pair, terminated},
+    IResult,
+};
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+const X_WIDTH: i64 = 101;
+const X_HALF: i64 = X_WIDTH / 2;
+const Y_WIDTH: i64 = 103;
+const Y_HALF: i64 = Y_WIDTH / 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Position {
+    x: i64,
+    y: i64,
+}
+
+struct Velocity {
+    x: i64,
+    y: i64,
+}
+
+pub struct Robot {
+    p: Position,
+    v: Velocity,
+}
+
+type RowData = Robot;
+
+fn parse_line(s: &str) -> IResult<&str, RowData> {
+    let (s, (px, py)) = preceded(
+        tag("p="),
+        separated_pair(complete::i64, char(','), complete::i64),
+    )(s)?;
+    let (s, (vx, vy)) = preceded(
+        tag(" v="),
+        separated_pair(complete::i64, char(','), complete::i64),
+    )(s)?;
+    Ok((
+        s,
+        Robot {
+            p: Position { x: px, y: py },
+            v: Velocity { x: vx, y: vy },
+        },
+    ))
+}
+
+fn parse_file(s: &str) -> IResult<&str, Vec<RowData>> {
+    let (s, (v, _)) = many_till(terminated(parse_line, line_ending), eof)(s)?;
+    Ok((s, v))
+}
+
+fn safety_factor(data: &[RowData]) -> u64 {
+    let mut totals = [0u64; 4];
+    data.iter()
+        .filter_map(|r| {
+            let mut x = (r.p.x + 100 * r.v.x) % X_WIDTH;
+            if x < 0 {
+                x += X_WIDTH;
+            }
+            let mut y = (r.p.y + 100 * r.v.y) % Y_WIDTH;
+            if y < 0 {
+                y += Y_WIDTH;
+            }
+            if x == X_HALF || y == Y_HALF {
+                None
+            } else {
+                Some(Position { x, y })
+            }
+        })
+        .for_each(|p| {
+            let idx = if p.x < X_HALF {
+                if p.y < Y_HALF {
+                    0
+                } else {
+                    1
+                }
+            } else {
+                if p.y < Y_HALF {
+                    2
+                } else {
+                    3
+                }
+            };
+            totals[idx] += 1;
+        });
+    totals.iter().copied().reduce(|acc, n| acc * n).unwrap()
+}
+
+fn positions(data: &[RowData], n: i64) -> impl Iterator<Item = Position> + '_ {
+    data.iter().map(move |r| {
+        let mut x = (r.p.x + n * r.v.x) % X_WIDTH;
+        if x < 0 {
+            x += X_WIDTH;
+        }
+        let mut y = (r.p.y + n * r.v.y) % Y_WIDTH;
+        if y < 0 {
+            y += Y_WIDTH;
+        }
+        Position { x, y }
+    })
+}
+
+#[allow(unused)]
+fn print_room(data: &[RowData], n: i64) {
+    let mut g = vec![vec!['.'; X_WIDTH as usize]; Y_WIDTH as usize];
+    for p in positions(data, n) {
+        // Y and X reversed because it draws the picture in the correct orientation
+        g[p.y as usize][p.x as usize] = '#';
+    }
+    for r in g {
+        println!("{}", r.iter().collect::<String>());
+    }
+}
+
+fn find_tree(data: &[RowData]) -> i64 {
+    // 10,000 iterations picked by brute force
+    (0..10000)
+        .into_par_iter()
+        .find_first(|&n| {
+            let mut s = HashSet::new();
+            // any overlapping robots signals that this isn't a picture, so move on
+            // I'd be lying if I said I didn't get this hint from someone online,
+            // this is not exactly a fair intuition
+            positions(data, n).all(|p| s.insert(p))
+        })
+        // there's only one that satisfies the HashSet heuristic, `print_room` to "carefully examine" it
+        .unwrap()
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Vec<RowData>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+            .map(|(_, v)| v)
+            .map_err(|e| ParseError::nom(s, e))
+    }
+}
+
+impl PartA for Solution {
+    type A = u64;
+
+    fn part_a(v: &Self::Input) -> Self::A {
+        safety_factor(v)
+    }
+}
+
+impl PartB for Solution {
+    type B = i64;
+
+    fn part_b(v: &Self::Input) -> Self::B {
+        find_tree(v)
+    }
+}
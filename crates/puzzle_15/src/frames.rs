@@ -0,0 +1,199 @@
+//! Watching [`solve`](crate::solve) move by move, to find the exact move where a push goes
+//! wrong. Each [`Observer`] gets the warehouse after every move. The ones here write the frames
+//! out as a text log, as the same log in color, or as numbered PPM or PGM images with an index.
+
+use crate::Warehouse;
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// The warehouse after a move, or before any with `n` 0.
+pub struct Frame<'a> {
+    pub n: usize,
+    pub total: usize,
+    /// `None` for the first frame.
+    pub dir: Option<(isize, isize)>,
+    /// Whether the robot got anywhere, it doesn't when the boxes are stuck.
+    pub moved: bool,
+    pub warehouse: &'a Warehouse,
+}
+
+impl Frame<'_> {
+    /// e.g. `move 3/15 >` or `move 4/15 ^ (blocked)`.
+    pub fn title(&self) -> String {
+        let mut s = format!("move {}/{}", self.n, self.total);
+        if let Some(dir) = self.dir {
+            write!(s, " {}", arrow(dir)).unwrap();
+        }
+        if !self.moved {
+            s.push_str(" (blocked)");
+        }
+        s
+    }
+}
+
+/// The inverse of [`direction`](crate::direction).
+pub fn arrow(dir: (isize, isize)) -> char {
+    match dir {
+        (0, -1) => '<',
+        (0, 1) => '>',
+        (-1, 0) => '^',
+        _ => 'v',
+    }
+}
+
+pub trait Observer {
+    fn frame(&mut self, frame: &Frame) -> io::Result<()>;
+}
+
+/// Watches nothing.
+impl Observer for () {
+    fn frame(&mut self, _: &Frame) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Every frame as plain text, a title, the map and the GPS. Flushed after each, so a push that
+/// panics still leaves the frames before it behind.
+pub struct Text<W>(pub W);
+
+impl<W: Write> Observer for Text<W> {
+    fn frame(&mut self, f: &Frame) -> io::Result<()> {
+        writeln!(self.0, "{}", f.title())?;
+        write!(self.0, "{}", f.warehouse)?;
+        writeln!(self.0, "gps: {}\n", f.warehouse.gps())?;
+        self.0.flush()
+    }
+}
+
+/// [`Text`] with ANSI colors: the robot red, boxes yellow, walls dim, and blocked moves in red
+/// so they stand out when scrolling through with `less -R`.
+pub struct Ansi<W>(pub W);
+
+impl<W: Write> Observer for Ansi<W> {
+    fn frame(&mut self, f: &Frame) -> io::Result<()> {
+        let title = match f.moved {
+            true => format!("\x1b[1m{}\x1b[0m", f.title()),
+            false => format!("\x1b[1;31m{}\x1b[0m", f.title()),
+        };
+        writeln!(self.0, "{title}")?;
+        let mut map = String::new();
+        for c in f.warehouse.to_string().chars() {
+            match c {
+                '@' => map.push_str("\x1b[1;31m@\x1b[0m"),
+                '#' => map.push_str("\x1b[2m#\x1b[0m"),
+                'O' | '[' | ']' | '-' => write!(map, "\x1b[33m{c}\x1b[0m").unwrap(),
+                c => map.push(c),
+            }
+        }
+        write!(self.0, "{map}")?;
+        writeln!(self.0, "gps: {}\n", f.warehouse.gps())?;
+        self.0.flush()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Color, `.ppm`.
+    Ppm,
+    /// Grayscale, `.pgm`.
+    Pgm,
+}
+
+/// Each frame as an image `frame_000000.ppm` (or `.pgm`) in a directory, `scale` pixels to a
+/// tile, and a line for it in `index.txt` with the move and the GPS.
+pub struct Images {
+    dir: PathBuf,
+    format: ImageFormat,
+    scale: usize,
+    index: BufWriter<File>,
+}
+
+impl Images {
+    /// Creates `dir` if it isn't there yet.
+    pub fn new(dir: impl AsRef<Path>, format: ImageFormat, scale: usize) -> io::Result<Self> {
+        let dir = dir.as_ref().to_owned();
+        fs::create_dir_all(&dir)?;
+        let index = BufWriter::new(File::create(dir.join("index.txt"))?);
+        Ok(Images {
+            dir,
+            format,
+            scale,
+            index,
+        })
+    }
+}
+
+impl Observer for Images {
+    fn frame(&mut self, f: &Frame) -> io::Result<()> {
+        let w = f.warehouse;
+        let (ext, magic) = match self.format {
+            ImageFormat::Ppm => ("ppm", "P6"),
+            ImageFormat::Pgm => ("pgm", "P5"),
+        };
+        let name = format!("frame_{:06}.{ext}", f.n);
+        let mut out = BufWriter::new(File::create(self.dir.join(&name))?);
+        let (height, width) = (w.height() * self.scale, w.width() * self.scale);
+        write!(out, "{magic}\n{width} {height}\n255\n")?;
+        for i in 0..height {
+            for j in 0..width {
+                let pos = (i / self.scale, j / self.scale);
+                let rgb = match () {
+                    _ if pos == w.robot() => [230, 60, 60],
+                    _ if w.is_wall(pos) => [90, 90, 90],
+                    _ if w.block_at(pos).is_some() => [200, 150, 50],
+                    _ => [20, 20, 20],
+                };
+                match self.format {
+                    ImageFormat::Ppm => out.write_all(&rgb)?,
+                    // the usual luma weights
+                    ImageFormat::Pgm => {
+                        let [r, g, b] = rgb.map(u32::from);
+                        out.write_all(&[((299 * r + 587 * g + 114 * b) / 1000) as u8])?
+                    }
+                }
+            }
+        }
+        out.flush()?;
+        writeln!(self.index, "{name} {} gps {}", f.title(), w.gps())?;
+        self.index.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{parse_map, solve};
+
+    #[test]
+    fn text_and_images() {
+        let map = parse_map("#####\n#.O.#\n#.@.#\n#####\n").unwrap();
+        let mut log = Text(vec![]);
+        assert_eq!(
+            solve(&map, &[(0, -1), (-1, 0)], 1, 1, &mut log).unwrap(),
+            102
+        );
+        assert_eq!(
+            String::from_utf8(log.0).unwrap(),
+            "move 0/2\n#####\n#.O.#\n#.@.#\n#####\ngps: 102\n\n\
+             move 1/2 <\n#####\n#.O.#\n#@..#\n#####\ngps: 102\n\n\
+             move 2/2 ^\n#####\n#@O.#\n#...#\n#####\ngps: 102\n\n"
+        );
+
+        let dir = std::env::temp_dir().join(format!("puzzle_15_frames_{}", std::process::id()));
+        let mut images = Images::new(&dir, ImageFormat::Pgm, 2).unwrap();
+        solve(&map, &[(-1, 0), (0, -1)], 1, 1, &mut images).unwrap();
+        let index = fs::read_to_string(dir.join("index.txt")).unwrap();
+        assert_eq!(
+            index,
+            "frame_000000.pgm move 0/2 gps 102\n\
+             frame_000001.pgm move 1/2 ^ (blocked) gps 102\n\
+             frame_000002.pgm move 2/2 < gps 102\n"
+        );
+        let image = fs::read(dir.join("frame_000002.pgm")).unwrap();
+        assert!(image.starts_with(b"P5\n10 8\n255\n"));
+        assert_eq!(image.len(), "P5\n10 8\n255\n".len() + 10 * 8);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,223 @@
+//! Pushing the boxes around by hand, with undo and redo, or watching the robot work through
+//! its own list of moves. Everything goes through plain bytes in and text out, so it runs the
+//! same in a terminal as it does with a script piped in.
+
+use crate::frames::{Frame, Observer};
+use crate::{direction, Warehouse};
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Key {
+    Move((isize, isize)),
+    Undo,
+    Redo,
+    Quit,
+}
+
+/// The keys in `bytes`: arrow keys, `<>^v`, `u` to undo, `r` to redo and `q` to quit.
+/// Anything else is skipped.
+pub fn keys(bytes: impl Iterator<Item = u8>) -> impl Iterator<Item = Key> {
+    let mut bytes = bytes.peekable();
+    std::iter::from_fn(move || loop {
+        let key = match bytes.next()? {
+            // arrow keys come as `ESC [ A` through `ESC [ D`
+            0x1b => {
+                if bytes.next_if_eq(&b'[').is_none() {
+                    continue;
+                }
+                match bytes.next()? {
+                    b'A' => Key::Move((-1, 0)),
+                    b'B' => Key::Move((1, 0)),
+                    b'C' => Key::Move((0, 1)),
+                    b'D' => Key::Move((0, -1)),
+                    _ => continue,
+                }
+            }
+            b'u' => Key::Undo,
+            b'r' => Key::Redo,
+            b'q' => Key::Quit,
+            c => match direction(c as char) {
+                Some(dir) => Key::Move(dir),
+                None => continue,
+            },
+        };
+        return Some(key);
+    })
+}
+
+/// Draws the warehouse with a status line under it. With `ansi` the screen is cleared first, so
+/// each frame lands on top of the last.
+fn draw(out: &mut impl Write, w: &Warehouse, status: &str, ansi: bool) -> io::Result<()> {
+    if ansi {
+        write!(out, "\x1b[H\x1b[2J")?;
+    }
+    write!(out, "{w}")?;
+    writeln!(out, "{status}")?;
+    out.flush()
+}
+
+/// A warehouse and every state it's been in, to step back and forth through.
+pub struct Playground {
+    current: Warehouse,
+    undo: Vec<Warehouse>,
+    redo: Vec<Warehouse>,
+}
+
+impl Playground {
+    pub fn new(w: Warehouse) -> Self {
+        Playground {
+            current: w,
+            undo: vec![],
+            redo: vec![],
+        }
+    }
+
+    pub fn warehouse(&self) -> &Warehouse {
+        &self.current
+    }
+
+    /// Moves that did something and haven't been undone.
+    pub fn moves(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// `false` on [`Key::Quit`]. Moves into a wall aren't kept, there's nothing to undo.
+    pub fn press(&mut self, key: Key) -> bool {
+        match key {
+            Key::Move(dir) => {
+                let before = self.current.clone();
+                if self.current.step(dir) {
+                    self.undo.push(before);
+                    self.redo.clear();
+                }
+            }
+            Key::Undo => {
+                if let Some(w) = self.undo.pop() {
+                    self.redo.push(std::mem::replace(&mut self.current, w));
+                }
+            }
+            Key::Redo => {
+                if let Some(w) = self.redo.pop() {
+                    self.undo.push(std::mem::replace(&mut self.current, w));
+                }
+            }
+            Key::Quit => return false,
+        }
+        true
+    }
+
+    fn draw(&self, out: &mut impl Write, ansi: bool) -> io::Result<()> {
+        let mut status = format!("gps: {}, moves: {}", self.current.gps(), self.moves());
+        if !self.redo.is_empty() {
+            status += &format!(", undone: {}", self.redo.len());
+        }
+        if ansi {
+            status += "\narrows or <>^v to move, u to undo, r to redo, q to quit";
+        }
+        draw(out, &self.current, &status, ansi)
+    }
+}
+
+/// Takes `keys` until they run out or one of them is `q`, drawing the warehouse after each.
+pub fn play(
+    w: Warehouse,
+    keys: impl Iterator<Item = Key>,
+    out: &mut impl Write,
+    ansi: bool,
+) -> io::Result<Playground> {
+    let mut p = Playground::new(w);
+    p.draw(out, ansi)?;
+    for key in keys {
+        if !p.press(key) {
+            break;
+        }
+        p.draw(out, ansi)?;
+    }
+    Ok(p)
+}
+
+/// Draws every frame, waiting `delay` before each after the first.
+struct Replay<'a, W> {
+    out: &'a mut W,
+    delay: Duration,
+    ansi: bool,
+}
+
+impl<W: Write> Observer for Replay<'_, W> {
+    fn frame(&mut self, f: &Frame) -> io::Result<()> {
+        if f.n > 0 {
+            thread::sleep(self.delay);
+        }
+        let w = f.warehouse;
+        let status = format!("gps: {}, move {}/{}", w.gps(), f.n, f.total);
+        draw(self.out, w, &status, self.ansi)
+    }
+}
+
+/// Runs through `moves`, drawing every step and waiting `delay` between them.
+pub fn replay(
+    mut w: Warehouse,
+    moves: &[(isize, isize)],
+    delay: Duration,
+    out: &mut impl Write,
+    ansi: bool,
+) -> io::Result<Warehouse> {
+    w.run(moves, &mut Replay { out, delay, ansi })?;
+    Ok(w)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse_map;
+
+    #[test]
+    fn scripted() {
+        let map = parse_map("#####\n#...#\n#.O.#\n#.@.#\n#####\n").unwrap();
+        let w = Warehouse::new(&map, 1, 1);
+        let mut out = vec![];
+        // up twice but the box sticks the second time, left with an arrow key, right, undo
+        // both, redo one and quit before the last move
+        let script = b"^^\x1b[D>uu x r q <";
+        let p = play(w, keys(script.iter().copied()), &mut out, false).unwrap();
+        assert_eq!(p.moves(), 2);
+        assert_eq!(p.warehouse().robot(), (2, 1));
+
+        let out = String::from_utf8(out).unwrap();
+        let status: Vec<_> = out.lines().filter(|l| l.starts_with("gps")).collect();
+        assert_eq!(
+            status,
+            [
+                "gps: 202, moves: 0",
+                "gps: 102, moves: 1",
+                "gps: 102, moves: 1",
+                "gps: 102, moves: 2",
+                "gps: 102, moves: 3",
+                "gps: 102, moves: 2, undone: 1",
+                "gps: 102, moves: 1, undone: 2",
+                "gps: 102, moves: 2, undone: 1",
+            ]
+        );
+        assert!(out.ends_with("#####\n#.O.#\n#@..#\n#...#\n#####\ngps: 102, moves: 2, undone: 1\n"));
+    }
+
+    #[test]
+    fn replaying() {
+        let (map, moves) = crate::parse_file(include_str!("../example1.txt")).unwrap();
+        let mut out = vec![];
+        let w = replay(
+            Warehouse::new(&map, 1, 1),
+            &moves,
+            Duration::ZERO,
+            &mut out,
+            true,
+        )
+        .unwrap();
+        assert_eq!(w.gps(), 2028);
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("\x1b[H\x1b[2J").count(), moves.len() + 1);
+        assert!(out.ends_with("gps: 2028, move 15/15\n"));
+    }
+}
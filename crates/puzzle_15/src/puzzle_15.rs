@@ -0,0 +1,331 @@
+use advent_core::{input, Day, ParseError, PartA, PartB};
+use frames::{Frame, Observer};
+use grid::{Grid, Pos};
+use std::fmt::{self, Display};
+use std::io;
+
+pub mod frames;
+pub mod playground;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Space {
+    Empty,
+    Wall,
+    Box,
+    Robot,
+}
+
+impl From<Space> for char {
+    fn from(s: Space) -> char {
+        match s {
+            Space::Empty => '.',
+            Space::Wall => '#',
+            Space::Box => 'O',
+            Space::Robot => '@',
+        }
+    }
+}
+
+pub type Map = Grid<Space>;
+pub type Moves = Vec<(isize, isize)>;
+
+// the map comes first in the file, so positions within it are positions within the file
+fn parse_map(s: &str) -> Result<Map, ParseError> {
+    let map = Grid::try_parse(s, "one of `.#O@`", |c| {
+        Some(match c {
+            '.' => Space::Empty,
+            '#' => Space::Wall,
+            'O' => Space::Box,
+            '@' => Space::Robot,
+            _ => return None,
+        })
+    })?;
+    if map.find(&Space::Robot).is_none() {
+        return Err(ParseError::eof(s, "a robot `@` on the map"));
+    }
+    Ok(map)
+}
+
+/// The move for one of `<>^v`.
+pub fn direction(c: char) -> Option<(isize, isize)> {
+    match c {
+        '<' => Some((0, -1)),
+        '>' => Some((0, 1)),
+        '^' => Some((-1, 0)),
+        'v' => Some((1, 0)),
+        _ => None,
+    }
+}
+
+fn parse_dirs(file: &str, s: &str) -> Result<Moves, ParseError> {
+    s.lines()
+        .flat_map(|l| {
+            l.char_indices()
+                .map(move |(j, c)| (c, &l[j..j + c.len_utf8()]))
+        })
+        .map(|(c, t)| direction(c).ok_or_else(|| ParseError::new(file, t, "one of `<>^v`")))
+        .collect()
+}
+
+fn parse_file(s: &str) -> Result<(Map, Moves), ParseError> {
+    let mut sections = input::sections(s);
+    let map = sections.next().unwrap_or_default();
+    let dirs = sections
+        .next()
+        .ok_or_else(|| ParseError::eof(s, "a blank line between the map and the moves"))?;
+    Ok((parse_map(map)?, parse_dirs(s, dirs)?))
+}
+
+/// A box, `height` by `width` tiles with its top left corner at `pos`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    pub pos: Pos,
+    pub height: usize,
+    pub width: usize,
+}
+
+impl Block {
+    fn tiles(self) -> impl Iterator<Item = Pos> {
+        let (i, j) = self.pos;
+        (i..i + self.height).flat_map(move |i| (j..j + self.width).map(move |j| (i, j)))
+    }
+}
+
+/// The warehouse with boxes of any size, which all get pushed around the same way.
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    walls: Grid<bool>,
+    /// Which of `blocks` covers each tile.
+    covered: Grid<Option<usize>>,
+    blocks: Vec<Block>,
+    robot: Pos,
+}
+
+impl Warehouse {
+    /// `map` with every tile blown up to `height` by `width` tiles. Part A's warehouse is 1 by 1
+    /// and part B's is 1 by 2. The robot stays one tile, in the top left of where it was.
+    pub fn new(map: &Map, height: usize, width: usize) -> Self {
+        let (h, w) = (map.height() * height, map.width() * width);
+        let mut walls = Grid::new(h, w, false);
+        let mut covered = Grid::new(h, w, None);
+        let mut blocks = vec![];
+        let mut robot = (0, 0);
+        for ((i, j), &s) in map.iter() {
+            let pos = (i * height, j * width);
+            let block = Block { pos, height, width };
+            match s {
+                Space::Empty => (),
+                Space::Wall => block.tiles().for_each(|p| walls[p] = true),
+                Space::Box => {
+                    block.tiles().for_each(|p| covered[p] = Some(blocks.len()));
+                    blocks.push(block);
+                }
+                Space::Robot => robot = pos,
+            }
+        }
+        Warehouse {
+            walls,
+            covered,
+            blocks,
+            robot,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.walls.height()
+    }
+
+    pub fn width(&self) -> usize {
+        self.walls.width()
+    }
+
+    pub fn robot(&self) -> Pos {
+        self.robot
+    }
+
+    pub fn blocks(&self) -> &[Block] {
+        &self.blocks
+    }
+
+    pub fn is_wall(&self, pos: Pos) -> bool {
+        self.walls[pos]
+    }
+
+    /// The box on `pos`, if there is one.
+    pub fn block_at(&self, pos: Pos) -> Option<&Block> {
+        self.covered[pos].map(|b| &self.blocks[b])
+    }
+
+    /// Moves the robot a tile in `dir`, pushing along every box in the way, and every box in
+    /// the way of those. If any of them would have to go into a wall nothing moves, and this
+    /// returns `false`.
+    pub fn step(&mut self, dir: (isize, isize)) -> bool {
+        let Some(next) = self.walls.offset(self.robot, dir) else {
+            return false;
+        };
+        let mut pushed = vec![];
+        let mut seen = vec![false; self.blocks.len()];
+        let mut frontier = vec![next];
+        while let Some(p) = frontier.pop() {
+            if self.walls[p] {
+                return false;
+            }
+            let Some(b) = self.covered[p] else {
+                continue;
+            };
+            if std::mem::replace(&mut seen[b], true) {
+                continue;
+            }
+            pushed.push(b);
+            for t in self.blocks[b].tiles() {
+                let Some(t) = self.walls.offset(t, dir) else {
+                    return false;
+                };
+                if self.covered[t] != Some(b) {
+                    frontier.push(t);
+                }
+            }
+        }
+
+        for &b in &pushed {
+            self.blocks[b].tiles().for_each(|t| self.covered[t] = None);
+        }
+        for &b in &pushed {
+            let block = &mut self.blocks[b];
+            block.pos = self.walls.offset(block.pos, dir).unwrap();
+            block.tiles().for_each(|t| self.covered[t] = Some(b));
+        }
+        self.robot = next;
+        true
+    }
+
+    /// Steps through `dirs`, showing `observer` the warehouse before the first and after every
+    /// one of them.
+    pub fn run(&mut self, dirs: &[(isize, isize)], observer: &mut impl Observer) -> io::Result<()> {
+        let total = dirs.len();
+        observer.frame(&Frame {
+            n: 0,
+            total,
+            dir: None,
+            moved: true,
+            warehouse: self,
+        })?;
+        for (n, &dir) in dirs.iter().enumerate() {
+            let moved = self.step(dir);
+            observer.frame(&Frame {
+                n: n + 1,
+                total,
+                dir: Some(dir),
+                moved,
+                warehouse: self,
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Every box's 100 times its top row plus its left column, which is how part B measures
+    /// the wide boxes too.
+    pub fn gps(&self) -> usize {
+        self.blocks.iter().map(|b| 100 * b.pos.0 + b.pos.1).sum()
+    }
+}
+
+/// Boxes one tile wide are `O`, wider ones `[`, `]` with `-` between.
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let map = self.covered.display_with(|pos, &b| match b {
+            _ if self.walls[pos] => '#',
+            _ if pos == self.robot => '@',
+            None => '.',
+            Some(b) => {
+                let block = self.blocks[b];
+                let (left, right) = (block.pos.1, block.pos.1 + block.width - 1);
+                match pos.1 {
+                    _ if left == right => 'O',
+                    j if j == left => '[',
+                    j if j == right => ']',
+                    _ => '-',
+                }
+            }
+        });
+        write!(f, "{map}")
+    }
+}
+
+/// The GPS after following `dirs` in `map` blown up to `height` by `width`. Only `observer` can
+/// fail, so with `&mut ()` this never does.
+pub fn solve(
+    map: &Map,
+    dirs: &[(isize, isize)],
+    height: usize,
+    width: usize,
+    observer: &mut impl Observer,
+) -> io::Result<usize> {
+    let mut warehouse = Warehouse::new(map, height, width);
+    warehouse.run(dirs, observer)?;
+    Ok(warehouse.gps())
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = (Map, Moves);
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = usize;
+
+    fn part_a((map, dirs): &Self::Input) -> Self::A {
+        solve(map, dirs, 1, 1, &mut ()).unwrap()
+    }
+}
+
+impl PartB for Solution {
+    type B = usize;
+
+    fn part_b((map, dirs): &Self::Input) -> Self::B {
+        solve(map, dirs, 1, 2, &mut ()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn steps(w: &mut Warehouse, moves: &str) -> Vec<bool> {
+        moves
+            .chars()
+            .map(|c| w.step(direction(c).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn wider_boxes() {
+        let map = parse_map("######\n#.O@.#\n#....#\n######\n").unwrap();
+        let mut w = Warehouse::new(&map, 1, 3);
+        assert_eq!(steps(&mut w, "<<<<"), [true, true, true, false]);
+        assert_eq!(w.gps(), 103);
+        assert_eq!(
+            w.to_string(),
+            "##################\n###[-]@........###\n###............###\n##################\n"
+        );
+    }
+
+    #[test]
+    fn pushing_a_pile() {
+        // the bottom box ends up half under each of the other two, and pushes both
+        let map = parse_map("#######\n#.....#\n#.OO..#\n#...O@#\n#.....#\n#######\n").unwrap();
+        let mut w = Warehouse::new(&map, 2, 4);
+        assert!(steps(&mut w, "<<<<<<vv<<").into_iter().all(|moved| moved));
+        assert_eq!(steps(&mut w, "^^^"), [true, true, false]);
+        assert_eq!(w.robot(), (6, 12));
+        let mut tops: Vec<_> = w.blocks().iter().map(|b| b.pos).collect();
+        tops.sort();
+        assert_eq!(tops, [(2, 8), (2, 12), (4, 10)]);
+        assert_eq!(w.gps(), 208 + 212 + 410);
+    }
+}
@@ -0,0 +1,367 @@
+use advent_core::{Answer, Day, ParseError, PartA, PartB};
+use grid::{Grid, Pos};
+use num_bigint::BigUint;
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+
+pub mod routes;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Space {
+    Empty,
+    Wall,
+    Start,
+    End,
+    /// Floor marked with this character, that takes this many steps' worth to walk onto, from
+    /// [`Costs::terrain`].
+    Terrain(char, usize),
+}
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir {
+    North = 0,
+    East = 1,
+    South = 2,
+    West = 3,
+}
+
+impl Dir {
+    const ALL: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    fn offset(&self) -> (isize, isize) {
+        match self {
+            Dir::North => (-1, 0),
+            Dir::East => (0, 1),
+            Dir::South => (1, 0),
+            Dir::West => (0, -1),
+        }
+    }
+}
+
+pub type State = (Pos, Dir);
+
+/// How the reindeer is scored. Turning always comes with a step in the new direction, so
+/// there's no turning on the spot, and no way around a forbidden reversal with two quarter
+/// turns either.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Costs {
+    pub step: usize,
+    /// A quarter turn, left or right.
+    pub turn: usize,
+    /// Turning right around, `None` if it isn't allowed at all.
+    pub reverse: Option<usize>,
+    /// Extra map characters for floor that's harder going, with what a step onto them is
+    /// multiplied by. These can't be any of `.#SE`, parsing fails if they are.
+    pub terrain: Vec<(char, usize)>,
+}
+
+/// The puzzle's scoring, 1 a step and 1000 a turn.
+impl Default for Costs {
+    fn default() -> Self {
+        Costs {
+            step: 1,
+            turn: 1000,
+            reverse: Some(2000),
+            terrain: vec![],
+        }
+    }
+}
+
+impl Costs {
+    /// Facing `from`, turning to `to` and stepping onto `onto`.
+    fn cost(&self, from: Dir, to: Dir, onto: Space) -> Option<usize> {
+        let turn = match (from as u8).abs_diff(to as u8) {
+            0 => 0,
+            1 | 3 => self.turn,
+            _ => self.reverse?,
+        };
+        let weight = match onto {
+            Space::Terrain(_, w) => w,
+            _ => 1,
+        };
+        Some(turn + self.step * weight)
+    }
+}
+
+/// The maze, with any of `costs`' terrain characters allowed in it as well as `.#SE`.
+pub fn parse_maze(s: &str, costs: &Costs) -> Result<Grid<Space>, ParseError> {
+    // pointed out wherever it shows up in the map, as the cost model has no place of its own
+    if let Some(&(c, _)) = costs.terrain.iter().find(|(c, _)| ".#SE".contains(*c)) {
+        let found = s.find(c).map_or(&s[..0], |i| &s[i..i + c.len_utf8()]);
+        return Err(ParseError::new(
+            s,
+            found,
+            format!("terrain marked with something other than `.#SE`, not `{c}`"),
+        ));
+    }
+    let extra: String = costs.terrain.iter().map(|&(c, _)| c).collect();
+    let map = Grid::try_parse(s, &format!("one of `.#SE{extra}`"), |c| {
+        Some(match c {
+            '.' => Space::Empty,
+            '#' => Space::Wall,
+            'S' => Space::Start,
+            'E' => Space::End,
+            c => Space::Terrain(c, costs.terrain.iter().find(|&&(t, _)| t == c)?.1),
+        })
+    })?;
+    if map.find(&Space::Start).is_none() {
+        return Err(ParseError::eof(s, "a start tile `S`"));
+    }
+    if map.find(&Space::End).is_none() {
+        return Err(ParseError::eof(s, "an end tile `E`"));
+    }
+    Ok(map)
+}
+
+fn neighbors<'a>(
+    data: &'a Grid<Space>,
+    costs: &'a Costs,
+    (pos, dir): State,
+) -> impl Iterator<Item = (State, usize)> + 'a {
+    Dir::ALL.into_iter().filter_map(move |d| {
+        let next = data.offset(pos, d.offset())?;
+        if data[next] == Space::Wall {
+            return None;
+        }
+        Some(((next, d), costs.cost(dir, d, data[next])?))
+    })
+}
+
+/// The moves along `path`: `F` for a step forward, with an `L` or `R` before it for each
+/// quarter turn.
+pub fn moves(path: &[State]) -> String {
+    let mut moves = String::new();
+    for w in path.windows(2) {
+        let ((_, from), (_, to)) = (w[0], w[1]);
+        moves += match (to as u8 + 4 - from as u8) % 4 {
+            0 => "",
+            1 => "R",
+            2 => "RR",
+            _ => "L",
+        };
+        moves.push('F');
+    }
+    moves
+}
+
+/// The maze with every tile in `tiles` marked `O`.
+pub fn render(data: &Grid<Space>, tiles: &HashSet<Pos>) -> String {
+    data.display_with(|p, &s| match s {
+        Space::Wall => '#',
+        Space::Start => 'S',
+        Space::End => 'E',
+        _ if tiles.contains(&p) => 'O',
+        Space::Terrain(c, _) => c,
+        Space::Empty => '.',
+    })
+    .to_string()
+}
+
+/// Every cheapest way from the start, facing east, to the end under `costs`.
+pub fn solve(data: &Grid<Space>, costs: &Costs) -> search::Paths<State, usize> {
+    let start = (data.find(&Space::Start).unwrap(), Dir::East);
+    search::dijkstra_all(
+        start,
+        |&s| neighbors(data, costs, s),
+        |&(p, _)| data[p] == Space::End,
+    )
+}
+
+/// The best score and one way of getting it, without keeping track of every tie like
+/// [`optimal`] does. `None` if there's no way to the end.
+pub fn cheapest(data: &Grid<Space>, costs: &Costs) -> Option<(usize, String)> {
+    let start = (data.find(&Space::Start).unwrap(), Dir::East);
+    let paths = search::dijkstra(
+        start,
+        |&s| neighbors(data, costs, s),
+        |&(p, _)| data[p] == Space::End,
+    );
+    let goal = paths.goal()?;
+    Some((paths.cost(goal)?, moves(&paths.path(goal)?)))
+}
+
+/// Everything about the cheapest ways through the maze.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Optimal {
+    pub score: usize,
+    /// One of them, as [`moves`].
+    pub moves: String,
+    /// How many different ones there are.
+    pub paths: BigUint,
+    /// Every tile on any of them.
+    pub tiles: HashSet<Pos>,
+}
+
+/// `None` if there's no way to the end.
+pub fn optimal(data: &Grid<Space>, costs: &Costs) -> Option<Optimal> {
+    let paths = solve(data, costs);
+    let goal = paths.goal()?;
+    Some(Optimal {
+        score: paths.cost(goal)?,
+        moves: moves(&paths.path(goal)?),
+        paths: paths.count_paths(paths.goals()),
+        tiles: paths
+            .on_optimal_paths(paths.goals())
+            .into_iter()
+            .map(|(p, _)| p)
+            .collect(),
+    })
+}
+
+/// Part A's answer, with one way of getting it. `None` if there's no way to the end.
+pub struct Score(Option<(usize, String)>);
+
+impl Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some((score, _)) => write!(f, "{score}"),
+            None => f.write_str("none"),
+        }
+    }
+}
+
+impl Answer for Score {
+    fn extra(&self) -> Vec<(&'static str, String)> {
+        match &self.0 {
+            Some((_, moves)) => vec![("moves", moves.clone())],
+            None => vec![],
+        }
+    }
+}
+
+/// Part B's answer, with how many best paths cover the seats and the seats on the map. `None`
+/// if there's no way to the end.
+pub struct Seats(Option<(Optimal, String)>);
+
+impl Display for Seats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some((o, _)) => write!(f, "{}", o.tiles.len()),
+            None => f.write_str("none"),
+        }
+    }
+}
+
+impl Answer for Seats {
+    fn extra(&self) -> Vec<(&'static str, String)> {
+        match &self.0 {
+            Some((o, map)) => vec![("paths", o.paths.to_string()), ("map", map.clone())],
+            None => vec![],
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Grid<Space>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_maze(s, &Costs::default())
+    }
+}
+
+impl PartA for Solution {
+    type A = Score;
+
+    fn part_a(v: &Self::Input) -> Self::A {
+        Score(cheapest(v, &Costs::default()))
+    }
+}
+
+impl PartB for Solution {
+    type B = Seats;
+
+    fn part_b(v: &Self::Input) -> Self::B {
+        Seats(optimal(v, &Costs::default()).map(|o| {
+            let map = render(v, &o.tiles);
+            (o, map)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn optimal_paths() {
+        let o = optimal(
+            &Solution::parse(include_str!("../example1.txt")).unwrap(),
+            &Costs::default(),
+        )
+        .unwrap();
+        assert_eq!(o.tiles.len(), 45);
+        let turns = o.moves.chars().filter(|&c| c != 'F').count();
+        let steps = o.moves.len() - turns;
+        assert_eq!(1000 * turns + steps, 7036);
+        assert_eq!(o.paths, BigUint::from(3u8));
+        let maze = Solution::parse(include_str!("../example1.txt")).unwrap();
+        assert_eq!(cheapest(&maze, &Costs::default()).unwrap().0, 7036);
+
+        // with free turns it's every way across a 40 by 40 room, 78 choose 39 of them
+        let mut maze = vec![vec!['.'; 40]; 40];
+        maze[0][0] = 'S';
+        maze[39][39] = 'E';
+        let maze: String = maze
+            .iter()
+            .map(|r| format!("{}\n", String::from_iter(r)))
+            .collect();
+        let costs = Costs {
+            turn: 0,
+            ..Costs::default()
+        };
+        let o = optimal(&parse_maze(&maze, &costs).unwrap(), &costs).unwrap();
+        assert_eq!(o.score, 78);
+        assert_eq!(o.paths.to_string(), "27217014869199032015600");
+        assert_eq!(o.tiles.len(), 40 * 40);
+    }
+
+    #[test]
+    fn other_costs() {
+        let example = include_str!("../example1.txt");
+        let o = optimal(&Solution::parse(example).unwrap(), &Costs::default()).unwrap();
+        assert_eq!(o.score, 7036);
+
+        // the end is behind the start
+        let costs = Costs {
+            terrain: vec![('~', 5)],
+            ..Costs::default()
+        };
+        let maze = parse_maze("#####\n#E~S#\n#####\n", &costs).unwrap();
+        assert_eq!(optimal(&maze, &costs).map(|o| o.score), Some(2000 + 5 + 1));
+        let costs = Costs {
+            step: 3,
+            reverse: None,
+            ..costs
+        };
+        assert_eq!(cheapest(&maze, &costs).map(|(score, _)| score), None);
+        let costs = Costs {
+            reverse: Some(10),
+            ..costs
+        };
+        assert_eq!(optimal(&maze, &costs).map(|o| o.score), Some(10 + 15 + 3));
+
+        assert_eq!(
+            render(&maze, &optimal(&maze, &costs).unwrap().tiles),
+            "#####\n#EOS#\n#####\n"
+        );
+        assert!(Solution::parse("#####\n#E~S#\n#####\n").is_err());
+        let clash = Costs {
+            terrain: vec![('~', 5), ('#', 2)],
+            ..Costs::default()
+        };
+        let err = parse_maze("#####\n#E~S#\n#####\n", &clash).unwrap_err();
+        assert_eq!(
+            err.expected(),
+            "terrain marked with something other than `.#SE`, not `#`"
+        );
+        let err = Solution::parse("#####\n#..S#\n#####\n").unwrap_err();
+        assert_eq!(err.expected(), "an end tile `E`");
+
+        let walled_off = Solution::parse("#####\n#S#E#\n#####\n").unwrap();
+        assert_eq!(Solution::part_a(&walled_off).to_string(), "none");
+        assert_eq!(Solution::part_b(&walled_off).to_string(), "none");
+    }
+}
@@ -0,0 +1,125 @@
+//! Routes through the maze besides the cheapest: the `k` best, and every tile that some route
+//! within a tolerance of the best score goes over.
+
+use super::{moves, neighbors, Costs, Dir, Space, State};
+use grid::{Grid, Pos};
+use std::collections::HashSet;
+
+/// One way through the maze.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub cost: usize,
+    /// As [`moves`](super::moves).
+    pub moves: String,
+    pub tiles: HashSet<Pos>,
+}
+
+/// The `k` cheapest routes from the start to the end, cheapest first, or fewer if there aren't
+/// that many. Routes that tie are all listed, so with several best paths the first few have the
+/// same cost. None of them come back to a tile facing the way they already went through it,
+/// so there's no pacing up and down to make up the numbers.
+pub fn best_routes(data: &Grid<Space>, costs: &Costs, k: usize) -> Vec<Route> {
+    let start = (data.find(&Space::Start).unwrap(), Dir::East);
+    search::k_shortest(
+        start,
+        |&s| neighbors(data, costs, s),
+        |&(p, _)| data[p] == Space::End,
+        k,
+    )
+    .into_iter()
+    .map(|(cost, path)| Route {
+        cost,
+        moves: moves(&path),
+        tiles: path.iter().map(|&(p, _)| p).collect(),
+    })
+    .collect()
+}
+
+/// The states `(pos, dir)` could have been reached from, and what that step cost.
+fn predecessors<'a>(
+    data: &'a Grid<Space>,
+    costs: &'a Costs,
+    (pos, dir): State,
+) -> impl Iterator<Item = (State, usize)> + 'a {
+    let (di, dj) = dir.offset();
+    let prev = data
+        .offset(pos, (-di, -dj))
+        .filter(|&p| data[p] != Space::Wall);
+    prev.into_iter().flat_map(move |prev| {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |d| Some(((prev, d), costs.cost(d, dir, data[pos])?)))
+    })
+}
+
+/// Every tile on a route that costs at most `tolerance` more than the best one. A state is on
+/// one when the cheapest way to it plus the cheapest way on from it is within that, so this
+/// takes one search forward from the start and one backward from the end.
+pub fn near_optimal_tiles(data: &Grid<Space>, costs: &Costs, tolerance: usize) -> HashSet<Pos> {
+    let start = (data.find(&Space::Start).unwrap(), Dir::East);
+    let from_start = search::dijkstra(start, |&s| neighbors(data, costs, s), |_| false);
+    // `None` stands for being at the end, facing any way
+    let to_end = search::dijkstra(
+        None,
+        |&s: &Option<State>| -> Vec<(Option<State>, usize)> {
+            match s {
+                None => match data.find(&Space::End) {
+                    Some(end) => Dir::ALL.map(|d| (Some((end, d)), 0)).to_vec(),
+                    None => vec![],
+                },
+                Some(s) => predecessors(data, costs, s)
+                    .map(|(p, c)| (Some(p), c))
+                    .collect(),
+            }
+        },
+        |_| false,
+    );
+
+    let Some(best) = to_end.cost(&Some(start)) else {
+        return HashSet::new();
+    };
+    from_start
+        .distances()
+        .filter(|&(s, c)| {
+            to_end
+                .cost(&Some(*s))
+                .is_some_and(|b| c + b <= best + tolerance)
+        })
+        .map(|(&(p, _), _)| p)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{optimal, Solution};
+    use advent_core::Day;
+
+    #[test]
+    fn beyond_the_best() {
+        let maze = Solution::parse(include_str!("../example1.txt")).unwrap();
+        let costs = Costs::default();
+        let best = optimal(&maze, &costs).unwrap();
+
+        let routes = best_routes(&maze, &costs, 4);
+        let scores: Vec<_> = routes.iter().map(|r| r.cost).collect();
+        assert_eq!(&scores[..3], [7036; 3]);
+        assert!(scores[3] > 7036);
+        let tied: HashSet<_> = routes[..3].iter().flat_map(|r| &r.tiles).copied().collect();
+        assert_eq!(tied, best.tiles);
+
+        let moves: HashSet<_> = routes.iter().map(|r| &r.moves).collect();
+        assert_eq!(moves.len(), 4);
+
+        // only the one way down a corridor
+        let corridor = Solution::parse("#####\n#S.E#\n#####\n").unwrap();
+        let only = best_routes(&corridor, &costs, 5);
+        assert_eq!(only.len(), 1);
+        assert_eq!((only[0].cost, only[0].moves.as_str()), (2, "FF"));
+
+        assert_eq!(near_optimal_tiles(&maze, &costs, 0), best.tiles);
+        let near = near_optimal_tiles(&maze, &costs, scores[3] - 7036);
+        assert!(near.is_superset(&routes[3].tiles));
+        assert!(near.len() > best.tiles.len());
+    }
+}
@@ -0,0 +1,230 @@
+use advent_core::{input, Answer, Day, ParseError, PartA, PartB};
+use std::fmt::{self, Display};
+use vm::{Machine, Stop, Word};
+
+fn parse_file(s: &str) -> Result<(u128, u128, u128, Vec<u8>), ParseError> {
+    let mut sections = input::sections(s);
+    let registers = sections.next().unwrap_or_default();
+    let program = sections.next().unwrap_or_default();
+    let mut ls = registers.lines().chain(program.lines());
+    let mut field = |prefix: &str| {
+        let l = ls
+            .next()
+            .ok_or_else(|| ParseError::eof(s, format!("a `{prefix}` line")))?;
+        l.strip_prefix(prefix)
+            .ok_or_else(|| ParseError::new(s, l, format!("a `{prefix}` line")))
+    };
+    let register = |t: &str| {
+        t.parse::<u128>()
+            .map_err(|_| ParseError::new(s, t, "a register value"))
+    };
+
+    let a = register(field("Register A: ")?)?;
+    let b = register(field("Register B: ")?)?;
+    let c = register(field("Register C: ")?)?;
+    let v = field("Program: ")?
+        .split(',')
+        .map(|i| {
+            i.parse()
+                .ok()
+                .filter(|&i| i < 8)
+                .ok_or_else(|| ParseError::new(s, i, "a 3-bit number"))
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+    Ok((a, b, c, v))
+}
+
+/// Plenty for a program that prints itself and halts, which only goes round its loop once per
+/// digit it prints.
+const QUINE_BUDGET: u64 = 1 << 20;
+
+/// Far more than any puzzle input takes, they go round their loop once for each digit they
+/// print.
+const RUN_BUDGET: u64 = 1 << 24;
+
+/// What the program printed, or why it stopped if it didn't halt.
+fn run_on<W: Word>(registers: [W; 3], p: &[u8], budget: u64) -> Result<Vec<u8>, Stop> {
+    let mut m = Machine::new(p.to_vec(), registers).with_budget(budget);
+    match m.run_until_halt() {
+        Stop::Halted => Ok(m.output().to_vec()),
+        stop => Err(stop),
+    }
+}
+
+/// On a `u64` machine when the registers fit, which they do for every puzzle input.
+fn run(a: u128, b: u128, c: u128, p: &[u8], budget: u64) -> Result<Vec<u8>, Stop> {
+    match [a, b, c].map(u64::try_from) {
+        [Ok(a), Ok(b), Ok(c)] => run_on([a, b, c], p, budget),
+        _ => run_on([a, b, c], p, budget),
+    }
+}
+
+/// The lowest A that makes the program print itself. The programs this works on shift A down
+/// three bits each time around their one loop and print something of what's left, so the last
+/// digit printed only depends on A's top three bits. A is built from there a digit at a time,
+/// keeping every 3-bit extension that makes the program print the tail of itself seen so far.
+/// Candidates that never halt are dropped once they use up [`QUINE_BUDGET`].
+fn find_quine(b: u128, c: u128, p: &[u8]) -> Option<u128> {
+    let mut candidates = vec![0u128];
+    for k in (0..p.len()).rev() {
+        candidates = candidates
+            .into_iter()
+            // anything that doesn't fit in a register is out
+            .filter_map(|a: u128| a.checked_mul(8))
+            .flat_map(|a| (0..8).map(move |d| a | d))
+            .filter(|&a| run(a, b, c, p, QUINE_BUDGET).is_ok_and(|out| out == p[k..]))
+            .collect();
+    }
+    candidates.into_iter().filter(|&a| a != 0).min()
+}
+
+/// Part A's answer, what the program printed. A program can stop without halting, on an
+/// instruction it can't run or by looping until it's out of [`RUN_BUDGET`], and then there's
+/// no answer.
+pub struct Printed(Result<Vec<u8>, Stop>);
+
+impl Display for Printed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Ok(out) => {
+                let out: Vec<_> = out.iter().map(ToString::to_string).collect();
+                f.write_str(&out.join(","))
+            }
+            Err(_) => f.write_str("none"),
+        }
+    }
+}
+
+impl Answer for Printed {
+    fn extra(&self) -> Vec<(&'static str, String)> {
+        match self.0 {
+            Ok(_) => vec![],
+            Err(Stop::Fault(fault)) => vec![("stopped", fault.to_string())],
+            Err(_) => vec![("stopped", format!("out of budget after {RUN_BUDGET} steps"))],
+        }
+    }
+}
+
+/// Part B's answer. Not every program can print itself, e.g. the first example's can't.
+pub struct Quine(Option<u128>);
+
+impl Display for Quine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(a) => write!(f, "{a}"),
+            None => f.write_str("none"),
+        }
+    }
+}
+
+impl Answer for Quine {}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = (u128, u128, u128, Vec<u8>);
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = Printed;
+
+    fn part_a(&(a, b, c, ref p): &Self::Input) -> Self::A {
+        Printed(run(a, b, c, p, RUN_BUDGET))
+    }
+}
+
+impl PartB for Solution {
+    type B = Quine;
+
+    fn part_b(&(_, b, c, ref p): &Self::Input) -> Self::B {
+        Quine(find_quine(b, c, p))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use vm::asm::assemble;
+
+    fn output(src: &str) -> String {
+        let input = Solution::parse(&assemble(src).unwrap().to_string()).unwrap();
+        Solution::part_a(&input).to_string()
+    }
+
+    #[test]
+    fn assembled() {
+        assert_eq!(output("A = 10\nout 0\nout 1\nout A"), "0,1,2");
+        assert_eq!(
+            output(
+                "A = 2024
+                 top: adv 1
+                      out A
+                      jnz top"
+            ),
+            "4,2,5,6,7,7,7,7,3,1,0"
+        );
+        // the second example, which prints itself when A starts at 117440
+        let src = "
+            A = 117440
+            loop:   adv 3
+                    out A   ; what's left of A after the shift
+                    jnz loop
+        ";
+        assert_eq!(output(src), "0,3,5,4,3,0");
+        let input = Solution::parse(&assemble(src).unwrap().to_string()).unwrap();
+        assert_eq!(Solution::part_b(&input).to_string(), "117440");
+    }
+
+    #[test]
+    fn wide_registers() {
+        // 2^70, shifted down 3 bits at a time
+        let input = Solution::parse(
+            "Register A: 1180591620717411303424\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0\n",
+        )
+        .unwrap();
+        assert_eq!(input.0, 1 << 70);
+        let out = Solution::part_a(&input).to_string();
+        assert_eq!(out, ["0"; 22].join(",") + ",2,0");
+        assert_eq!(
+            output("A = 1180591620717411303424\nadv 3\nadv 3\nout A"),
+            "0"
+        );
+    }
+
+    #[test]
+    fn never_halting() {
+        // every A but 0 jumps back to the start forever
+        let input =
+            Solution::parse("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n")
+                .unwrap();
+        assert_eq!(Solution::part_a(&input).to_string(), "");
+        assert_eq!(Solution::part_b(&input).to_string(), "none");
+        let input =
+            Solution::parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n")
+                .unwrap();
+        let a = Solution::part_a(&input);
+        assert_eq!(a.to_string(), "none");
+        assert_eq!(
+            a.extra(),
+            [("stopped", "out of budget after 16777216 steps".to_owned())]
+        );
+    }
+
+    #[test]
+    fn reserved_combo() {
+        // parses fine, it's only running it that goes wrong
+        let input =
+            Solution::parse("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 1,7,5,7\n")
+                .unwrap();
+        let a = Solution::part_a(&input);
+        assert_eq!(a.to_string(), "none");
+        assert_eq!(
+            a.extra(),
+            [("stopped", "reserved combo operand 7 at 2".to_owned())]
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use advent_core::{Answer, Day, ParseError, PartA, PartB};
+use grid::{Grid, Pos};
+use nom::{
+    character::complete::{self, char, line_ending},
+    combinator::{eof, map},
+    multi::many_till,
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+use rayon::prelude::*;
+use search::Paths;
+use std::fmt::{self, Display};
+
+// (board size, bytes fallen) for the example and the real input
+const EXAMPLE: (usize, usize) = (7, 12);
+const INPUT: (usize, usize) = (71, 1024);
+type RowData = (usize, usize);
+
+fn parse_line(s: &str) -> IResult<&str, RowData> {
+    map(
+        separated_pair(complete::u32, char(','), complete::u32),
+        |(a, b)| (1 + b as usize, 1 + a as usize),
+    )(s)
+}
+
+fn parse_bytes(s: &str) -> IResult<&str, Vec<RowData>> {
+    let (s, (v, _)) = many_till(terminated(parse_line, line_ending), eof)(s)?;
+    Ok((s, v))
+}
+
+/// Every byte has to land inside the board, and there have to be enough of them for part A.
+fn parse_file(s: &str) -> Result<Vec<RowData>, ParseError> {
+    let (_, v) = parse_bytes(s).map_err(|e| ParseError::nom(s, e))?;
+    let (dim, fallen) = board_size(&v);
+    // one line a byte
+    if let Some((l, _)) = s.lines().zip(&v).find(|(_, &(x, y))| x > dim || y > dim) {
+        let expected = format!("a byte from 0,0 to {0},{0}", dim - 1);
+        return Err(ParseError::new(s, l, expected));
+    }
+    if v.len() < fallen {
+        return Err(ParseError::eof(s, format!("at least {fallen} bytes")));
+    }
+    Ok(v)
+}
+
+fn shortest_path(board: &Grid<bool>, dim: usize) -> Paths<Pos, usize> {
+    search::bfs(
+        (1, 1),
+        |&p| board.neighbors4(p).filter(|&n| !board[n]),
+        |&p| p == (dim, dim),
+    )
+}
+
+fn board_size(data: &[RowData]) -> (usize, usize) {
+    // coordinates are already shifted by one for the border
+    if data.iter().all(|&(x, y)| x <= EXAMPLE.0 && y <= EXAMPLE.0) {
+        EXAMPLE
+    } else {
+        INPUT
+    }
+}
+
+fn is_blocked(data: &[RowData], dim: usize, n: usize) -> bool {
+    let mut board = Grid::new(dim, dim, false).padded(true);
+
+    for &(x, y) in &data[0..n] {
+        board[(x, y)] = true;
+    }
+
+    shortest_path(&board, dim).goal().is_none()
+}
+
+/// `None` if the bytes that have fallen already cut off the exit.
+fn min_steps(data: &[RowData]) -> Option<usize> {
+    let (dim, sim_n) = board_size(data);
+    let mut board = Grid::new(dim, dim, false).padded(true);
+
+    for &(x, y) in &data[0..sim_n] {
+        board[(x, y)] = true;
+    }
+
+    shortest_path(&board, dim).cost(&(dim, dim))
+}
+
+/// Part A's answer, if there's still a way out.
+pub struct Steps(Option<usize>);
+
+impl Display for Steps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(n) => write!(f, "{n}"),
+            None => f.write_str("none"),
+        }
+    }
+}
+
+impl Answer for Steps {}
+
+/// The first byte that cuts the exit off, `index` counting from 0 down the input. `None` if
+/// the exit is still open once they've all fallen.
+pub struct Blocker(Option<Byte>);
+
+pub struct Byte {
+    x: usize,
+    y: usize,
+    index: usize,
+}
+
+impl Display for Blocker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(b) => write!(f, "{},{}", b.x, b.y),
+            None => f.write_str("none"),
+        }
+    }
+}
+
+impl Answer for Blocker {
+    fn extra(&self) -> Vec<(&'static str, String)> {
+        let Some(b) = &self.0 else {
+            return vec![];
+        };
+        vec![
+            ("x", b.x.to_string()),
+            ("y", b.y.to_string()),
+            ("index", b.index.to_string()),
+        ]
+    }
+}
+
+fn first_blocker(data: &[RowData]) -> Blocker {
+    let (dim, _) = board_size(data);
+    // the fewest bytes that block it, counting all of them
+    let blocked = (1..=data.len())
+        .into_par_iter()
+        .filter(|&n| is_blocked(data, dim, n))
+        .min();
+    Blocker(blocked.map(|n| {
+        // board coordinates are (row, col) shifted by the border, the answer wants (X, Y)
+        let (x, y) = data[n - 1];
+        Byte {
+            x: y - 1,
+            y: x - 1,
+            index: n - 1,
+        }
+    }))
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Vec<RowData>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = Steps;
+
+    fn part_a(v: &Self::Input) -> Self::A {
+        Steps(min_steps(v))
+    }
+}
+
+impl PartB for Solution {
+    type B = Blocker;
+
+    fn part_b(v: &Self::Input) -> Self::B {
+        first_blocker(v)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bad_bytes() {
+        let expected = |s| Solution::parse(s).unwrap_err().expected().to_owned();
+        assert_eq!(expected(""), "at least 12 bytes");
+        assert_eq!(expected("1,1\n2,2\n"), "at least 12 bytes");
+        assert_eq!(expected("1,1\n71,3\n"), "a byte from 0,0 to 70,70");
+
+        // the exit itself is the first byte
+        let bytes = format!("6,6\n{}", "3,3\n".repeat(11));
+        let v = Solution::parse(&bytes).unwrap();
+        assert_eq!(Solution::part_a(&v).to_string(), "none");
+        assert_eq!(Solution::part_b(&v).to_string(), "6,6");
+
+        // only the last byte blocks it, after part A's 12
+        let mut bytes = "3,3\n".repeat(12);
+        let v = Solution::parse(&bytes).unwrap();
+        assert_eq!(Solution::part_a(&v).to_string(), "12");
+        assert_eq!(Solution::part_b(&v).to_string(), "none");
+        bytes += "6,6\n";
+        let v = Solution::parse(&bytes).unwrap();
+        assert_eq!(Solution::part_a(&v).to_string(), "12");
+        assert_eq!(Solution::part_b(&v).to_string(), "6,6");
+    }
+}
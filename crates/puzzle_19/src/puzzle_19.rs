@@ -0,0 +1,135 @@
+use advent_core::{input, Day, ParseError, PartA, PartB};
+use std::collections::HashMap;
+
+fn parse_towels(s: &str) -> HashMap<String, bool> {
+    s.split(", ").map(|s| (s.to_string(), true)).collect()
+}
+
+// the towel colors, white, blue, black, red and green
+fn check_colors<'a>(file: &str, t: &'a str) -> Result<&'a str, ParseError> {
+    match t.char_indices().find(|&(_, c)| !"wubrg".contains(c)) {
+        Some((i, c)) => Err(ParseError::new(
+            file,
+            &t[i..i + c.len_utf8()],
+            "one of the colors `wubrg`",
+        )),
+        None => Ok(t),
+    }
+}
+
+fn parse_file(s: &str) -> Result<(HashMap<String, bool>, Vec<String>), ParseError> {
+    let mut sections = input::sections(s);
+    let ts = sections
+        .next()
+        .ok_or_else(|| ParseError::eof(s, "a list of towels"))?;
+    let ts = ts.trim_end();
+    if let Some(l) = ts.lines().nth(1) {
+        return Err(ParseError::new(s, l, "a blank line after the towels"));
+    }
+    for t in ts.split(", ") {
+        check_colors(s, t)?;
+    }
+    let ds = sections
+        .next()
+        .unwrap_or_default()
+        .lines()
+        .map(|d| check_colors(s, d).map(ToOwned::to_owned))
+        .collect::<Result<_, _>>()?;
+    Ok((parse_towels(ts), ds))
+}
+
+fn can_make(
+    design: &str,
+    towels: &HashMap<String, bool>,
+    cache: &mut HashMap<String, bool>,
+) -> bool {
+    if design.is_empty() {
+        return true;
+    }
+
+    if let Some(r) = cache.get(design) {
+        return *r;
+    }
+
+    for towel in towels.keys() {
+        if let Some(d) = design.strip_prefix(towel) {
+            if can_make(d, towels, cache) {
+                cache.insert(d.to_string(), true);
+                return true;
+            }
+        }
+    }
+
+    cache.insert(design.to_string(), false);
+    false
+}
+
+fn count_possible(towels: &HashMap<String, bool>, designs: &[String]) -> u64 {
+    let mut total = 0;
+    let mut cache = HashMap::new();
+    for design in designs {
+        if can_make(design, towels, &mut cache) {
+            total += 1;
+        }
+    }
+    total
+}
+
+fn count_ways(
+    design: &str,
+    towels: &HashMap<String, bool>,
+    cache: &mut HashMap<String, u64>,
+) -> u64 {
+    if design.is_empty() {
+        return 1;
+    }
+
+    if let Some(r) = cache.get(design) {
+        return *r;
+    }
+
+    let mut total = 0;
+    for towel in towels.keys() {
+        if let Some(d) = design.strip_prefix(towel) {
+            total += count_ways(d, towels, cache);
+        }
+    }
+
+    cache.insert(design.to_string(), total);
+    total
+}
+
+fn count_arrangements(towels: &HashMap<String, bool>, designs: &[String]) -> u64 {
+    let mut total = 0;
+    let mut cache = HashMap::new();
+    for design in designs {
+        total += count_ways(design, towels, &mut cache);
+    }
+    total
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = (HashMap<String, bool>, Vec<String>);
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = u64;
+
+    fn part_a((towels, designs): &Self::Input) -> Self::A {
+        count_possible(towels, designs)
+    }
+}
+
+impl PartB for Solution {
+    type B = u64;
+
+    fn part_b((towels, designs): &Self::Input) -> Self::B {
+        count_arrangements(towels, designs)
+    }
+}
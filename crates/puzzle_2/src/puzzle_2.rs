@@ -1,11 +1,41 @@
-use std::fs;
+use advent_core::{Day, ParseError, PartA, PartB};
 
 fn is_unsafe_move(greater: i64, lesser: i64) -> bool {
     let diff = greater - lesser;
-    diff < 1 || diff > 3
+    !(1..=3).contains(&diff)
 }
 
 fn is_safe(report: &[i64]) -> bool {
+    if report.len() == 1 {
+        return true;
+    }
+
+    if report[0] < report[1] {
+        // increasing
+        for i in 1..report.len() {
+            if is_unsafe_move(report[i], report[i - 1]) {
+                return false;
+            }
+        }
+        true
+    } else {
+        // decreasing
+        for i in 1..report.len() {
+            if is_unsafe_move(report[i - 1], report[i]) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn count_safe(reports: &[Vec<i64>]) -> u64 {
+    reports
+        .iter()
+        .fold(0, |total, level| total + is_safe(level) as u64)
+}
+
+fn is_safe_dampened(report: &[i64]) -> bool {
     is_safe_impl(report, true)
 }
 
@@ -77,11 +107,9 @@ fn is_safe_decreasing(report: &[i64], mut mulligan: bool) -> bool {
     true
 }
 
-fn count_safe(reports: &[Vec<i64>]) -> u64 {
+fn count_safe_dampened(reports: &[Vec<i64>]) -> u64 {
     reports.iter().fold(0, |total, level| {
-        if is_safe(level) {
-            total + 1
-        } else if is_safe_retry(level) {
+        if is_safe_dampened(level) || is_safe_retry(level) {
             total + 1
         } else {
             total
@@ -89,17 +117,41 @@ fn count_safe(reports: &[Vec<i64>]) -> u64 {
     })
 }
 
-fn main() {
-    let args: Vec<_> = std::env::args().collect();
-    let input = &args[1];
-    let data = fs::read_to_string(input).unwrap();
-    let report: Vec<_> = data
-        .lines()
+fn parse_file(s: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    s.lines()
         .map(|line| {
             line.split_ascii_whitespace()
-                .map(|s| s.parse::<i64>().unwrap())
+                .map(|t| {
+                    t.parse::<i64>()
+                        .map_err(|_| ParseError::new(s, t, "a number"))
+                })
                 .collect()
         })
-        .collect();
-    println!("{}", count_safe(&report));
+        .collect()
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = u64;
+
+    fn part_a(report: &Self::Input) -> Self::A {
+        count_safe(report)
+    }
+}
+
+impl PartB for Solution {
+    type B = u64;
+
+    fn part_b(report: &Self::Input) -> Self::B {
+        count_safe_dampened(report)
+    }
 }
@@ -0,0 +1,194 @@
+use advent_core::{Day, ParseError, PartA, PartB};
+use grid::{Grid, Pos};
+
+fn parse_file(s: &str) -> Result<Grid<i64>, ParseError> {
+    let track = Grid::try_parse(s, "one of `#.SE`", |c| {
+        Some(match c {
+            '#' => -1,
+            '.' => 0,
+            'S' => 1,
+            'E' => i64::MAX,
+            _ => return None,
+        })
+    })?;
+    if track.find(&1).is_none() {
+        return Err(ParseError::eof(s, "a start tile `S`"));
+    }
+    if track.find(&i64::MAX).is_none() {
+        return Err(ParseError::eof(s, "an end tile `E`"));
+    }
+    check_track(s, &track)?;
+    Ok(track)
+}
+
+/// The track has to be one path from S to E without forks, and every tile that isn't a wall
+/// has to be on it, as that's what the cheats are counted along.
+fn check_track(s: &str, track: &Grid<i64>) -> Result<(), ParseError> {
+    // every tile is one byte, parsing only lets `#.SE` through
+    let tile = |(i, j): Pos| &s.lines().nth(i).unwrap()[j..j + 1];
+    let mut on_track = Grid::new(track.height(), track.width(), false);
+    let mut pos = track.find(&1).unwrap();
+    while track[pos] != i64::MAX {
+        on_track[pos] = true;
+        let mut next = track
+            .neighbors4(pos)
+            .filter(|&n| track[n] != -1 && !on_track[n]);
+        pos = match (next.next(), next.next()) {
+            (Some(n), None) => n,
+            (None, _) => return Err(ParseError::new(s, tile(pos), "a track that goes on to `E`")),
+            (Some(_), Some(_)) => {
+                return Err(ParseError::new(s, tile(pos), "a track that doesn't fork"))
+            }
+        };
+    }
+    on_track[pos] = true;
+    match track.positions().find(|&p| track[p] != -1 && !on_track[p]) {
+        Some(p) => Err(ParseError::new(s, tile(p), "a wall `#` off the track")),
+        None => Ok(()),
+    }
+}
+
+/// Numbers every tile of the track by when the race gets there, from 1 at the start, and
+/// returns the tiles in race order.
+fn race(track: &Grid<i64>) -> (Grid<i64>, Vec<Pos>) {
+    let mut track = track.clone();
+    let mut pos = track.find(&1).unwrap();
+    let mut steps = vec![];
+    let mut curr = 2i64;
+    loop {
+        steps.push(pos);
+        // parsing checked the track goes all the way to the end
+        let next = track
+            .neighbors4(pos)
+            .find(|&n| track[n] == 0 || track[n] == i64::MAX)
+            .unwrap();
+        let finished = track[next] == i64::MAX;
+        track[next] = curr;
+        curr += 1;
+        pos = next;
+        if finished {
+            steps.push(pos);
+            break;
+        }
+    }
+    (track, steps)
+}
+
+/// Everywhere a 2 picosecond cheat can end up.
+const CHEATS: [(isize, isize); 8] = [
+    (-2, 0),
+    (2, 0),
+    (0, -2),
+    (0, 2),
+    (-1, -1),
+    (-1, 1),
+    (1, -1),
+    (1, 1),
+];
+
+fn dist(a: Pos, b: Pos) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+fn count_short_cheats(track: &Grid<i64>) -> u64 {
+    let (track, steps) = race(track);
+
+    let mut total = 0;
+    for pos in steps.into_iter().rev() {
+        let v = track[pos];
+        assert_ne!(v, 0);
+        for p1 in CHEATS.into_iter().filter_map(|d| track.offset(pos, d)) {
+            let v1 = track[p1];
+            if v1 == -1 {
+                continue;
+            }
+            assert_ne!(v1, 0);
+
+            if v - v1 - 2 >= 100 {
+                total += 1;
+            }
+        }
+    }
+
+    total
+}
+
+fn count_long_cheats(track: &Grid<i64>) -> u64 {
+    let (track, steps) = race(track);
+    steps
+        .into_iter()
+        .map(|(x, y)| {
+            let v = track[(x, y)];
+            assert_ne!(v, 0);
+
+            (x.saturating_sub(20)..=x + 20)
+                .flat_map(|x1| (y.saturating_sub(20)..=y + 20).map(move |y1| (x1, y1)))
+                .filter_map(|p1| track.get(p1).map(|&v1| (p1, v1)))
+                .filter_map(|(p1, v1)| {
+                    let d = dist((x, y), p1);
+                    let saved = v - v1 - d as i64;
+                    if v1 != -1 && d <= 20 && saved >= 100 {
+                        Some(1)
+                    } else {
+                        None
+                    }
+                })
+                .sum::<u64>() // how to break HM in 23 easy lines
+        })
+        .sum()
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Grid<i64>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = u64;
+
+    fn part_a(track: &Self::Input) -> Self::A {
+        count_short_cheats(track)
+    }
+}
+
+impl PartB for Solution {
+    type B = u64;
+
+    fn part_b(track: &Self::Input) -> Self::B {
+        count_long_cheats(track)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bad_tracks() {
+        let err = Solution::parse("#####\n#S#E#\n#####\n").unwrap_err();
+        assert_eq!((err.line(), err.col()), (2, 2));
+        assert_eq!(err.expected(), "a track that goes on to `E`");
+
+        let err = Solution::parse("#####\n#..##\n#S#E#\n#####\n").unwrap_err();
+        assert_eq!((err.line(), err.col()), (2, 3));
+        assert_eq!(err.expected(), "a track that goes on to `E`");
+        let err = Solution::parse("#####\n#.S.#\n#.#E#\n#####\n").unwrap_err();
+        assert_eq!((err.line(), err.col()), (2, 3));
+        assert_eq!(err.expected(), "a track that doesn't fork");
+
+        let err = Solution::parse("#####\n#S.E#\n#.###\n#####\n").unwrap_err();
+        assert_eq!((err.line(), err.col()), (2, 2));
+        let err = Solution::parse("#####\n#S.E#\n###.#\n#####\n").unwrap_err();
+        assert_eq!((err.line(), err.col()), (3, 4));
+        assert_eq!(err.expected(), "a wall `#` off the track");
+
+        let track = Solution::parse("#####\n#S.E#\n#####\n").unwrap();
+        assert_eq!(Solution::part_a(&track), 0);
+        assert_eq!(Solution::part_b(&track), 0);
+    }
+}
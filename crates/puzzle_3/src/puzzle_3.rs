@@ -0,0 +1,113 @@
+use advent_core::{Day, ParseError, PartA, PartB};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, char},
+    error::{make_error, ErrorKind},
+    sequence::{delimited, separated_pair},
+    Err, IResult,
+};
+
+fn parse_dont(s: &str) -> IResult<&str, ()> {
+    let (s, _) = tag("don't()")(s)?;
+    Ok((s, ()))
+}
+
+fn parse_mul(s: &str) -> IResult<&str, u64> {
+    let (s, _) = tag("mul")(s)?;
+    let (s2, (a, b)) = delimited(
+        char('('),
+        separated_pair(complete::u64, char(','), complete::u64),
+        char(')'),
+    )(s)?;
+    if a > 999 || b > 999 {
+        return Err(Err::Error(make_error(s, ErrorKind::Fail)));
+    }
+    Ok((s2, a * b))
+}
+
+/// `s` without its first character, which needn't be ASCII.
+fn skip_char(s: &str) -> &str {
+    &s[s.chars().next().map_or(0, char::len_utf8)..]
+}
+
+fn sum_muls(data: &str) -> u64 {
+    let mut s = data;
+    let mut total = 0;
+    while !s.is_empty() {
+        if let Ok((new_s, n)) = parse_mul(s) {
+            total += n;
+            s = new_s;
+        } else {
+            s = skip_char(s);
+        }
+    }
+    total
+}
+
+fn sum_enabled_muls(data: &str) -> u64 {
+    let mut s = data;
+    let mut total = 0;
+    let mut enabled = true;
+    while !s.is_empty() {
+        if enabled {
+            if let Ok((new_s, ())) = parse_dont(s) {
+                enabled = false;
+                s = new_s;
+                continue;
+            }
+            if let Ok((new_s, n)) = parse_mul(s) {
+                total += n;
+                s = new_s;
+                continue;
+            }
+        } else {
+            let Some((_, b)) = s.split_once("do()") else {
+                break;
+            };
+            enabled = true;
+            s = b;
+            continue;
+        }
+        s = skip_char(s);
+    }
+    total
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = String;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        // the corrupted memory is scanned rather than parsed, anything goes
+        Ok(s.to_owned())
+    }
+}
+
+impl PartA for Solution {
+    type A = u64;
+
+    fn part_a(data: &Self::Input) -> Self::A {
+        sum_muls(data)
+    }
+}
+
+impl PartB for Solution {
+    type B = u64;
+
+    fn part_b(data: &Self::Input) -> Self::B {
+        sum_enabled_muls(data)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn not_ascii() {
+        let data = "é€mul(2,3)🦀don't()mul(4,5)ü do()mul(1,1)";
+        assert_eq!(sum_muls(data), 6 + 20 + 1);
+        assert_eq!(sum_enabled_muls(data), 6 + 1);
+    }
+}
@@ -0,0 +1,85 @@
+use advent_core::{Day, ParseError, PartA, PartB};
+use grid::{Grid, Pos, DIRS8};
+fn parse_file(s: &str) -> Result<Grid<char>, ParseError> {
+    Grid::try_parse(s, "a letter", |c| c.is_alphabetic().then_some(c))
+}
+
+fn is_xmas(text: &Grid<char>, pos: Pos, (di, dj): (isize, isize)) -> bool {
+    let xmas = ['M', 'A', 'S'];
+    xmas.iter().zip(1..).all(|(&c, k)| {
+        text.offset(pos, (di * k, dj * k))
+            .is_some_and(|p| text[p] == c)
+    })
+}
+
+fn find_xmas(text: &Grid<char>) -> u64 {
+    let mut total = 0;
+    for (pos, &c) in text.iter() {
+        if c != 'X' {
+            continue;
+        }
+        for dir in DIRS8 {
+            total += is_xmas(text, pos, dir) as u64;
+        }
+    }
+    total
+}
+
+fn is_x_mas(text: &Grid<char>, (i, j): Pos) -> bool {
+    let at = |i, j| text[(i, j)];
+    ((at(i - 1, j - 1) == 'M' && at(i + 1, j + 1) == 'S')
+        || (at(i - 1, j - 1) == 'S' && at(i + 1, j + 1) == 'M'))
+        && ((at(i - 1, j + 1) == 'M' && at(i + 1, j - 1) == 'S')
+            || (at(i - 1, j + 1) == 'S' && at(i + 1, j - 1) == 'M'))
+}
+
+fn find_x_mas(text: &Grid<char>) -> u64 {
+    let mut total = 0;
+    for ((i, j), &c) in text.iter() {
+        if i == 0 || i == text.height() - 1 || j == 0 || j == text.width() - 1 {
+            continue;
+        }
+        if c != 'A' {
+            continue;
+        }
+        total += is_x_mas(text, (i, j)) as u64;
+    }
+    total
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Grid<char>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = u64;
+
+    fn part_a(v: &Self::Input) -> Self::A {
+        find_xmas(v)
+    }
+}
+
+impl PartB for Solution {
+    type B = u64;
+
+    fn part_b(v: &Self::Input) -> Self::B {
+        find_x_mas(v)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ragged_rows() {
+        let err = Solution::parse("XMAS\nXM\n").unwrap_err();
+        assert_eq!((err.line(), err.expected()), (2, "a row 4 wide"));
+    }
+}
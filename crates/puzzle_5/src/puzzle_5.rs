@@ -0,0 +1,104 @@
+use advent_core::{input, Day, ParseError, PartA, PartB};
+use std::collections::{HashMap, HashSet};
+
+type Rules = HashMap<u64, HashSet<u64>>;
+
+fn parse_file(file: &str) -> Result<(Rules, Vec<Vec<u64>>), ParseError> {
+    let num = |t: &str| {
+        t.parse::<u64>()
+            .map_err(|_| ParseError::new(file, t, "a page number"))
+    };
+    let mut sections = input::sections(file);
+    let rules = sections.next().unwrap_or_default();
+    let updates = sections.next().unwrap_or_default();
+    let mut m: Rules = HashMap::new();
+    for l in rules.lines() {
+        let (a, b) = l
+            .split_once('|')
+            .ok_or_else(|| ParseError::new(file, l, "a rule like `47|53`"))?;
+        m.entry(num(a)?).or_default().insert(num(b)?);
+    }
+    let v = updates
+        .lines()
+        .map(|l| l.split(',').map(num).collect())
+        .collect::<Result<_, _>>()?;
+    Ok((m, v))
+}
+
+fn sum_correct(rules: &HashMap<u64, HashSet<u64>>, pages: &[Vec<u64>]) -> u64 {
+    let mut total = 0;
+    'page: for page in pages {
+        for (i, n) in page.iter().enumerate() {
+            if let Some(rule) = rules.get(n) {
+                for j in (0..i).rev() {
+                    if rule.get(&page[j]).is_some() {
+                        continue 'page;
+                    }
+                }
+            }
+        }
+        total += page[page.len() / 2];
+    }
+    total
+}
+
+fn sum_reordered(rules: &HashMap<u64, HashSet<u64>>, pages: &[Vec<u64>]) -> u64 {
+    let mut total = 0;
+    let mut incorrect_pages = vec![];
+    'page: for page in pages {
+        for (i, n) in page.iter().enumerate() {
+            if let Some(rule) = rules.get(n) {
+                for j in (0..i).rev() {
+                    if rule.get(&page[j]).is_some() {
+                        incorrect_pages.push(page.clone());
+                        continue 'page;
+                    }
+                }
+            }
+        }
+    }
+
+    for mut page in incorrect_pages {
+        'page: loop {
+            for (i, n) in page.iter().enumerate() {
+                if let Some(rule) = rules.get(n) {
+                    for j in (0..i).rev() {
+                        if rule.get(&page[j]).is_some() {
+                            page.swap(i, j);
+                            continue 'page;
+                        }
+                    }
+                }
+            }
+            total += page[page.len() / 2];
+            break;
+        }
+    }
+    total
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = (Rules, Vec<Vec<u64>>);
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = u64;
+
+    fn part_a((m, s): &Self::Input) -> Self::A {
+        sum_correct(m, s)
+    }
+}
+
+impl PartB for Solution {
+    type B = u64;
+
+    fn part_b((m, s): &Self::Input) -> Self::B {
+        sum_reordered(m, s)
+    }
+}
@@ -0,0 +1,456 @@
+use advent_core::{Answer, Day, ParseError, PartA, PartB};
+use grid::{Grid, Pos, DIRS4};
+use rayon::prelude::*;
+use std::fmt::{self, Display};
+
+pub mod sim;
+
+fn parse_file(file: &str) -> Result<Lab, ParseError> {
+    let mut tiles = Grid::try_parse(file, "one of `.#^>v<`", |c| {
+        Some(match c {
+            '.' => Space::Empty,
+            '#' => Space::Obstacle,
+            '^' => Space::Guard(GuardDirection::Up),
+            '>' => Space::Guard(GuardDirection::Right),
+            'v' => Space::Guard(GuardDirection::Down),
+            '<' => Space::Guard(GuardDirection::Left),
+            _ => return None,
+        })
+    })?;
+    let mut guards = vec![];
+    for (pos, s) in tiles.iter_mut() {
+        if let Space::Guard(dir) = *s {
+            guards.push((pos, dir));
+            *s = Space::Empty;
+        }
+    }
+    if guards.is_empty() {
+        return Err(ParseError::eof(file, "a guard somewhere on the map"));
+    }
+    Ok(Lab { tiles, guards })
+}
+
+/// In the same order as [`DIRS4`], so turning right is the next one along.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GuardDirection {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl GuardDirection {
+    const ALL: [GuardDirection; 4] = [
+        GuardDirection::Up,
+        GuardDirection::Right,
+        GuardDirection::Down,
+        GuardDirection::Left,
+    ];
+
+    fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    fn offset(self) -> (isize, isize) {
+        DIRS4[self as usize]
+    }
+
+    fn vertical(self) -> bool {
+        matches!(self, GuardDirection::Up | GuardDirection::Down)
+    }
+}
+
+impl From<GuardDirection> for char {
+    fn from(dir: GuardDirection) -> char {
+        match dir {
+            GuardDirection::Up => '^',
+            GuardDirection::Right => '>',
+            GuardDirection::Down => 'v',
+            GuardDirection::Left => '<',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Space {
+    #[default]
+    Empty,
+    Obstacle,
+    /// Only while parsing, the guards' tiles are emptied once they've been found.
+    Guard(GuardDirection),
+}
+
+pub struct Lab {
+    tiles: Grid<Space>,
+    /// Row by row. The puzzle itself only ever has the one.
+    guards: Vec<(Pos, GuardDirection)>,
+}
+
+impl Lab {
+    /// The guard both parts follow, the first on the map.
+    fn guard(&self) -> (Pos, GuardDirection) {
+        self.guards[0]
+    }
+}
+
+/// Which ways the guard went across a tile.
+#[derive(Debug, Clone, Copy, Default)]
+struct Trail {
+    vertical: bool,
+    horizontal: bool,
+}
+
+impl Trail {
+    fn mark(&mut self, dir: GuardDirection) {
+        if dir.vertical() {
+            self.vertical = true;
+        } else {
+            self.horizontal = true;
+        }
+    }
+}
+
+struct Route {
+    /// Every tile the guard walks over, in the order it first gets to them, with the way it was
+    /// facing as it stepped on. The first is where it starts.
+    path: Vec<(Pos, GuardDirection)>,
+    trail: Grid<Trail>,
+}
+
+fn walk(lab: &Lab) -> Route {
+    let mut trail = Grid::new(lab.tiles.height(), lab.tiles.width(), Trail::default());
+    let mut seen = Grid::new(lab.tiles.height(), lab.tiles.width(), false);
+    let mut turns = Bitset::new(lab.tiles.height() * lab.tiles.width() * 4);
+    let (mut pos, mut dir) = lab.guard();
+    seen[pos] = true;
+    trail[pos].mark(dir);
+    let mut path = vec![(pos, dir)];
+    while let Some(next) = lab.tiles.offset(pos, dir.offset()) {
+        if lab.tiles[next] == Space::Obstacle {
+            dir = dir.turn_right();
+            trail[pos].mark(dir);
+            // the guard never leaves if it turns the same way on the same tile twice
+            if !turns.insert(state(&lab.tiles, pos, dir)) {
+                break;
+            }
+            continue;
+        }
+        pos = next;
+        trail[pos].mark(dir);
+        if !seen[pos] {
+            seen[pos] = true;
+            path.push((pos, dir));
+        }
+    }
+    Route { path, trail }
+}
+
+/// The map with the guard's route drawn on it like the puzzle does, `|` and `-` for the way it
+/// went and `+` where it went both ways, and an `O` on each of `marks`.
+fn render(lab: &Lab, route: &Route, marks: &[Pos]) -> String {
+    let (start, dir) = lab.guard();
+    let mut marked = Grid::new(lab.tiles.height(), lab.tiles.width(), false);
+    marks.iter().for_each(|&p| marked[p] = true);
+    let map = lab.tiles.display_with(|pos, space| {
+        let t = route.trail[pos];
+        match space {
+            _ if marked[pos] => 'O',
+            _ if pos == start => dir.into(),
+            Space::Obstacle => '#',
+            _ if t.vertical && t.horizontal => '+',
+            _ if t.vertical => '|',
+            _ if t.horizontal => '-',
+            _ => '.',
+        }
+    });
+    map.to_string()
+}
+
+fn state(tiles: &Grid<Space>, (i, j): Pos, dir: GuardDirection) -> usize {
+    (i * tiles.width() + j) * 4 + dir as usize
+}
+
+struct Bitset(Vec<u64>);
+
+impl Bitset {
+    fn new(len: usize) -> Self {
+        Bitset(vec![0; len.div_ceil(64)])
+    }
+
+    /// `false` if `i` was already in there.
+    fn insert(&mut self, i: usize) -> bool {
+        let (word, bit) = (i / 64, 1 << (i % 64));
+        let new = self.0[word] & bit == 0;
+        self.0[word] |= bit;
+        new
+    }
+}
+
+/// The obstacles in each row and column, sorted, so the guard can go straight to the next one
+/// instead of a tile at a time.
+struct Obstacles {
+    rows: Vec<Vec<usize>>,
+    columns: Vec<Vec<usize>>,
+}
+
+impl Obstacles {
+    fn new(tiles: &Grid<Space>) -> Self {
+        let mut rows = vec![vec![]; tiles.height()];
+        let mut columns = vec![vec![]; tiles.width()];
+        // `iter` goes row by row, so both come out sorted
+        for ((i, j), s) in tiles.iter() {
+            if *s == Space::Obstacle {
+                rows[i].push(j);
+                columns[j].push(i);
+            }
+        }
+        Obstacles { rows, columns }
+    }
+
+    /// Where the guard ends up walking from `pos` until something's in the way, with `extra`
+    /// counted as an obstacle too. `None` if it walks off the map instead.
+    fn next_stop(&self, (i, j): Pos, dir: GuardDirection, extra: Pos) -> Option<Pos> {
+        let (line, at, extra) = match dir {
+            GuardDirection::Up | GuardDirection::Down => {
+                (&self.columns[j], i, (extra.1 == j).then_some(extra.0))
+            }
+            GuardDirection::Left | GuardDirection::Right => {
+                (&self.rows[i], j, (extra.0 == i).then_some(extra.1))
+            }
+        };
+        let k = line.partition_point(|&o| o < at);
+        let blocked = match dir {
+            GuardDirection::Up | GuardDirection::Left => {
+                let before = k.checked_sub(1).map(|k| line[k]);
+                [before, extra.filter(|&o| o < at)]
+                    .into_iter()
+                    .flatten()
+                    .max()?
+                    + 1
+            }
+            GuardDirection::Down | GuardDirection::Right => {
+                let after = line.get(k).copied();
+                [after, extra.filter(|&o| o > at)]
+                    .into_iter()
+                    .flatten()
+                    .min()?
+                    - 1
+            }
+        };
+        Some(match dir {
+            GuardDirection::Up | GuardDirection::Down => (blocked, j),
+            GuardDirection::Left | GuardDirection::Right => (i, blocked),
+        })
+    }
+}
+
+/// Whether the guard goes round in circles with an obstacle added at `extra`. Only the tiles
+/// it turns on are tracked, a loop has to come back to one of them facing the same way.
+fn has_loop(
+    lab: &Lab,
+    obstacles: &Obstacles,
+    mut pos: Pos,
+    mut dir: GuardDirection,
+    extra: Pos,
+) -> bool {
+    let mut turns = Bitset::new(lab.tiles.height() * lab.tiles.width() * 4);
+    while let Some(stop) = obstacles.next_stop(pos, dir, extra) {
+        pos = stop;
+        dir = dir.turn_right();
+        if !turns.insert(state(&lab.tiles, pos, dir)) {
+            return true;
+        }
+    }
+    false
+}
+
+/// Part A's answer, with the route drawn on the map.
+pub struct Visited {
+    count: usize,
+    map: String,
+}
+
+impl Display for Visited {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.count)
+    }
+}
+
+impl Answer for Visited {
+    fn extra(&self) -> Vec<(&'static str, String)> {
+        vec![("map", self.map.clone())]
+    }
+}
+
+/// Part B's answer, every obstacle that traps the guard as (row, col) and marked on the map.
+pub struct Loops {
+    obstacles: Vec<Pos>,
+    map: String,
+}
+
+impl Display for Loops {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.obstacles.len())
+    }
+}
+
+impl Answer for Loops {
+    fn extra(&self) -> Vec<(&'static str, String)> {
+        let obstacles: Vec<_> = self
+            .obstacles
+            .iter()
+            .map(|(i, j)| format!("({i}, {j})"))
+            .collect();
+        vec![
+            ("obstacles", obstacles.join(", ")),
+            ("map", self.map.clone()),
+        ]
+    }
+}
+
+fn visited(lab: &Lab) -> Visited {
+    let route = walk(lab);
+    Visited {
+        count: route.path.len(),
+        map: render(lab, &route, &[]),
+    }
+}
+
+fn loops(lab: &Lab) -> Loops {
+    let obstacles = Obstacles::new(&lab.tiles);
+    let route = walk(lab);
+    // an obstacle anywhere off the guard's path changes nothing. One on it only changes things
+    // from the first time the guard would have stepped there, so the walk starts from the tile
+    // before that
+    let mut found: Vec<_> = route.path[1..]
+        .par_iter()
+        .filter(|&&(o, dir)| {
+            let (di, dj) = dir.offset();
+            let before = lab.tiles.offset(o, (-di, -dj)).unwrap();
+            has_loop(lab, &obstacles, before, dir, o)
+        })
+        .map(|&(o, _)| o)
+        .collect();
+    found.sort_unstable();
+    Loops {
+        map: render(lab, &route, &found),
+        obstacles: found,
+    }
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Lab;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = Visited;
+
+    fn part_a(lab: &Self::Input) -> Self::A {
+        visited(lab)
+    }
+}
+
+impl PartB for Solution {
+    type B = Loops;
+
+    fn part_b(lab: &Self::Input) -> Self::B {
+        loops(lab)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Lab {
+        Solution::parse(include_str!("../example.txt")).unwrap()
+    }
+
+    #[test]
+    fn route_drawn() {
+        let a = Solution::part_a(&example());
+        assert_eq!(a.count, 41);
+        assert_eq!(
+            a.map,
+            "....#.....\n\
+             ....+---+#\n\
+             ....|...|.\n\
+             ..#.|...|.\n\
+             ..+-+-+#|.\n\
+             ..|.|.|.|.\n\
+             .#+-^-+-+.\n\
+             .+----++#.\n\
+             #+----+|..\n\
+             ......#|..\n"
+        );
+    }
+
+    #[test]
+    fn looping_obstacles() {
+        let b = Solution::part_b(&example());
+        assert_eq!(
+            b.obstacles,
+            [(6, 3), (7, 6), (7, 7), (8, 1), (8, 3), (9, 7)]
+        );
+        assert_eq!(
+            b.map,
+            "....#.....\n\
+             ....+---+#\n\
+             ....|...|.\n\
+             ..#.|...|.\n\
+             ..+-+-+#|.\n\
+             ..|.|.|.|.\n\
+             .#+O^-+-+.\n\
+             .+----OO#.\n\
+             #O-O--+|..\n\
+             ......#O..\n"
+        );
+    }
+
+    #[test]
+    fn next_stop() {
+        let lab = example();
+        let obstacles = Obstacles::new(&lab.tiles);
+        // (9, 9) is out of the way of every move here
+        let none = (9, 9);
+        assert_eq!(
+            obstacles.next_stop((6, 4), GuardDirection::Up, none),
+            Some((1, 4))
+        );
+        assert_eq!(
+            obstacles.next_stop((6, 4), GuardDirection::Up, (3, 4)),
+            Some((4, 4))
+        );
+        assert_eq!(
+            obstacles.next_stop((1, 4), GuardDirection::Right, none),
+            Some((1, 8))
+        );
+        assert_eq!(
+            obstacles.next_stop((8, 3), GuardDirection::Left, none),
+            Some((8, 1))
+        );
+        assert_eq!(
+            obstacles.next_stop((8, 3), GuardDirection::Left, (8, 1)),
+            Some((8, 2))
+        );
+        // the extra one only counts ahead of the guard
+        assert_eq!(
+            obstacles.next_stop((5, 7), GuardDirection::Down, (3, 7)),
+            None
+        );
+        assert_eq!(
+            obstacles.next_stop((1, 8), GuardDirection::Down, none),
+            Some((6, 8))
+        );
+    }
+}
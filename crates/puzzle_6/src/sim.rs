@@ -0,0 +1,287 @@
+//! The guards' patrol with the rules up for grabs, for trying out variations on the puzzle:
+//! which way they turn, whether the map's edges wrap around, and any number of guards walking
+//! at once.
+//!
+//! Every step, each guard still on the map either turns, if there's an obstacle in front of
+//! it, or takes a step forward. Guards that end a step on the same tile, or walk through each
+//! other, collide and stop where they are, and stop getting in anyone's way.
+
+use super::{GuardDirection, Lab, Space};
+use grid::{Grid, Pos};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Turn {
+    Left,
+    #[default]
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Edges {
+    /// Walking off the map is the end of that guard's patrol.
+    #[default]
+    Exit,
+    /// Walking off one side comes back on the other.
+    Wrap,
+}
+
+/// The puzzle's own rules by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Rules {
+    pub turn: Turn,
+    pub edges: Edges,
+    /// Stop after this many steps, if nothing else has stopped it by then.
+    pub step_limit: Option<usize>,
+}
+
+/// How a guard's patrol ended, with the step it happened on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fate {
+    Exited(usize),
+    Collided {
+        step: usize,
+        with: usize,
+    },
+    /// Still walking, the same way forever.
+    Looping,
+    /// Still walking when the step limit ran out.
+    Walking,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardReport {
+    pub start: Pos,
+    /// Distinct tiles walked on, counting where it started.
+    pub visited: usize,
+    pub fate: Fate,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// In the same order as the guards are on the map, row by row.
+    pub guards: Vec<GuardReport>,
+    pub steps: usize,
+    /// How many steps it takes to come back around, if the guards left were found going in
+    /// circles.
+    pub cycle: Option<usize>,
+}
+
+struct Guard {
+    /// `None` once it's off the map or collided.
+    at: Option<(Pos, GuardDirection)>,
+    seen: Grid<bool>,
+    visited: usize,
+    fate: Fate,
+}
+
+impl Guard {
+    fn visit(&mut self, pos: Pos) {
+        if !std::mem::replace(&mut self.seen[pos], true) {
+            self.visited += 1;
+        }
+    }
+}
+
+fn ahead(tiles: &Grid<Space>, (i, j): Pos, dir: GuardDirection, edges: Edges) -> Option<Pos> {
+    match edges {
+        Edges::Exit => tiles.offset((i, j), dir.offset()),
+        Edges::Wrap => {
+            let (di, dj) = dir.offset();
+            let (h, w) = (tiles.height() as isize, tiles.width() as isize);
+            Some((
+                (i as isize + di).rem_euclid(h) as usize,
+                (j as isize + dj).rem_euclid(w) as usize,
+            ))
+        }
+    }
+}
+
+/// Runs every guard on `lab` until they've all stopped, they're found to be going round in
+/// circles, or the step limit runs out.
+pub fn simulate(lab: &Lab, rules: &Rules) -> Report {
+    let tiles = &lab.tiles;
+    let mut guards: Vec<_> = lab
+        .guards
+        .iter()
+        .map(|&(pos, dir)| {
+            let mut g = Guard {
+                at: Some((pos, dir)),
+                seen: Grid::new(tiles.height(), tiles.width(), false),
+                visited: 0,
+                fate: Fate::Walking,
+            };
+            g.visit(pos);
+            g
+        })
+        .collect();
+
+    // Brent's cycle finding, so there's only ever one old state kept to compare against
+    let state = |guards: &[Guard]| guards.iter().map(|g| g.at).collect::<Vec<_>>();
+    let mut saved = state(&guards);
+    let (mut power, mut lambda) = (1, 0);
+    let mut steps = 0;
+    let mut cycle = None;
+
+    while guards.iter().any(|g| g.at.is_some()) && rules.step_limit != Some(steps) {
+        steps += 1;
+        let before = state(&guards);
+        for g in &mut guards {
+            let Some((pos, dir)) = g.at else {
+                continue;
+            };
+            g.at = match ahead(tiles, pos, dir, rules.edges) {
+                None => {
+                    g.fate = Fate::Exited(steps);
+                    None
+                }
+                Some(next) if tiles[next] == Space::Obstacle => Some(match rules.turn {
+                    Turn::Left => (pos, dir.turn_left()),
+                    Turn::Right => (pos, dir.turn_right()),
+                }),
+                Some(next) => {
+                    g.visit(next);
+                    Some((next, dir))
+                }
+            };
+        }
+
+        let after = state(&guards);
+        let collided: Vec<_> = (0..guards.len())
+            .filter_map(|a| {
+                let (pos, _) = after[a]?;
+                let with = (0..guards.len()).find(|&b| {
+                    let (Some((p, _)), Some((q, _))) = (after[b], before[b]) else {
+                        return false;
+                    };
+                    // on the same tile, or swapping tiles
+                    b != a && (p == pos || (q == pos && Some(p) == before[a].map(|(s, _)| s)))
+                })?;
+                Some((a, with))
+            })
+            .collect();
+        for (a, with) in collided {
+            guards[a].at = None;
+            guards[a].fate = Fate::Collided { step: steps, with };
+        }
+
+        let now = state(&guards);
+        lambda += 1;
+        if now == saved {
+            cycle = Some(lambda);
+            break;
+        }
+        if lambda == power {
+            saved = now;
+            power *= 2;
+            lambda = 0;
+        }
+    }
+
+    if cycle.is_some() {
+        for g in &mut guards {
+            if g.at.is_some() {
+                g.fate = Fate::Looping;
+            }
+        }
+    }
+    Report {
+        guards: guards
+            .into_iter()
+            .zip(&lab.guards)
+            .map(|(g, &(start, _))| GuardReport {
+                start,
+                visited: g.visited,
+                fate: g.fate,
+            })
+            .collect(),
+        steps,
+        cycle,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Solution;
+    use advent_core::Day;
+
+    fn run(map: &str, rules: Rules) -> Report {
+        simulate(&Solution::parse(map).unwrap(), &rules)
+    }
+
+    #[test]
+    fn puzzle_rules() {
+        let example = include_str!("../example.txt");
+        let r = run(example, Rules::default());
+        assert_eq!(r.guards[0].visited, 41);
+        assert_eq!(r.guards[0].fate, Fate::Exited(r.steps));
+        assert_eq!(r.cycle, None);
+
+        let r = run(
+            example,
+            Rules {
+                step_limit: Some(10),
+                ..Rules::default()
+            },
+        );
+        assert_eq!(r.steps, 10);
+        assert_eq!(r.guards[0].fate, Fate::Walking);
+    }
+
+    #[test]
+    fn turning_left() {
+        // right would send it off to the right, left keeps it going round
+        let map = "\
+...#.
+#....
+...^.
+....#
+.#...
+";
+        let r = run(
+            map,
+            Rules {
+                turn: Turn::Left,
+                ..Rules::default()
+            },
+        );
+        assert_eq!(r.guards[0].fate, Fate::Looping);
+        assert_eq!(r.guards[0].visited, 8);
+        assert!(r.cycle.is_some());
+
+        let r = run(map, Rules::default());
+        assert!(matches!(r.guards[0].fate, Fate::Exited(_)));
+    }
+
+    #[test]
+    fn wraparound() {
+        let r = run(
+            "..\n^.\n",
+            Rules {
+                edges: Edges::Wrap,
+                ..Rules::default()
+            },
+        );
+        assert_eq!(r.guards[0].fate, Fate::Looping);
+        assert_eq!(r.guards[0].visited, 2);
+        assert_eq!(r.cycle, Some(2));
+    }
+
+    #[test]
+    fn collisions() {
+        // meet in the middle
+        let r = run(">...<\n", Rules::default());
+        assert_eq!(r.guards[0].fate, Fate::Collided { step: 2, with: 1 });
+        assert_eq!(r.guards[1].fate, Fate::Collided { step: 2, with: 0 });
+        assert_eq!(r.guards[0].visited, 3);
+
+        // walk through each other
+        let r = run(".><.\n", Rules::default());
+        assert_eq!(r.guards[0].fate, Fate::Collided { step: 1, with: 1 });
+
+        // one gets out of the way in time
+        let r = run(">..\n..^\n", Rules::default());
+        assert_eq!(r.guards[0].fate, Fate::Exited(3));
+        assert_eq!(r.guards[1].fate, Fate::Exited(2));
+    }
+}
@@ -1,11 +1,11 @@
+use advent_core::{Day, ParseError, PartA, PartB};
 use nom::{
     character::complete::{self, char, line_ending, space1},
-    combinator::{all_consuming, eof},
-    multi::{many0_count, many1},
+    combinator::eof,
+    multi::{many1, many_till},
     sequence::{preceded, terminated, tuple},
     IResult,
 };
-use std::fs;
 
 type RowData = (u64, Vec<u64>);
 
@@ -17,12 +17,11 @@ fn parse_line(s: &str) -> IResult<&str, RowData> {
         )),
         line_ending,
     )(s)?;
-    Ok((s, row.into()))
+    Ok((s, row))
 }
 
 fn parse_file(s: &str) -> IResult<&str, Vec<RowData>> {
-    let (s, v) = many1(parse_line)(s)?;
-    let (s, _) = all_consuming(preceded(many0_count(line_ending), eof))(s)?;
+    let (s, (v, _)) = many_till(parse_line, eof)(s)?;
     Ok((s, v))
 }
 
@@ -45,18 +44,19 @@ impl Op {
         }
     }
 
-    pub fn next(arr: &mut [Op]) -> bool {
+    /// Steps `arr` to the next combination, `Or` only takes part when `concat` is set.
+    pub fn next(arr: &mut [Op], concat: bool) -> bool {
         for op in arr {
             match op {
                 Op::Add => {
                     *op = Op::Mul;
                     return true;
                 }
-                Op::Mul => {
+                Op::Mul if concat => {
                     *op = Op::Or;
                     return true;
                 }
-                Op::Or => {
+                Op::Mul | Op::Or => {
                     *op = Op::Add;
                 }
             }
@@ -66,7 +66,7 @@ impl Op {
     }
 }
 
-fn solve(data: &[(u64, Vec<u64>)]) -> u64 {
+fn solve(data: &[(u64, Vec<u64>)], concat: bool) -> u64 {
     let mut total: u64 = 0;
     for (res, nums) in data {
         let mut ops = vec![Op::Add; nums.len() - 1];
@@ -80,7 +80,7 @@ fn solve(data: &[(u64, Vec<u64>)]) -> u64 {
                 total = total.checked_add(output).unwrap();
                 break;
             }
-            if !Op::next(&mut ops) {
+            if !Op::next(&mut ops, concat) {
                 break;
             }
         }
@@ -88,10 +88,30 @@ fn solve(data: &[(u64, Vec<u64>)]) -> u64 {
     total
 }
 
-fn main() {
-    let args: Vec<_> = std::env::args().collect();
-    let input = &args[1];
-    let data = fs::read_to_string(input).unwrap();
-    let (_, v) = parse_file(&data).unwrap();
-    println!("{}", solve(&v));
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Vec<RowData>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+            .map(|(_, v)| v)
+            .map_err(|e| ParseError::nom(s, e))
+    }
+}
+
+impl PartA for Solution {
+    type A = u64;
+
+    fn part_a(v: &Self::Input) -> Self::A {
+        solve(v, false)
+    }
+}
+
+impl PartB for Solution {
+    type B = u64;
+
+    fn part_b(v: &Self::Input) -> Self::B {
+        solve(v, true)
+    }
 }
@@ -0,0 +1,128 @@
+use advent_core::{Day, ParseError, PartA, PartB};
+use grid::{Grid, Pos};
+use std::collections::{HashMap, HashSet};
+
+fn parse_file(s: &str) -> Result<Grid<char>, ParseError> {
+    Grid::try_parse(s, "`.` or an antenna", |c| {
+        (c == '.' || c.is_ascii_alphanumeric()).then_some(c)
+    })
+}
+
+type Point = Pos;
+type PointPair = (Point, Point);
+
+fn antennas(data: &Grid<char>) -> HashMap<char, Vec<Point>> {
+    let mut points: HashMap<char, Vec<Point>> = HashMap::new();
+    data.iter().filter(|(_, &c)| c != '.').for_each(|(p, c)| {
+        points.entry(*c).or_insert(vec![]).push(p);
+    });
+    points
+}
+
+fn each_pair<'a>(data: &'a [Point]) -> impl Iterator<Item = PointPair> + 'a {
+    data.iter()
+        .copied()
+        .enumerate()
+        .flat_map(|(i, p)| std::iter::repeat(p).zip(data.iter().copied().skip(i + 1)))
+}
+
+fn antinodes(data: &Grid<char>, p: &PointPair) -> Vec<Point> {
+    let x = (p.0 .0 as isize) - (p.1 .0 as isize);
+    let y = (p.0 .1 as isize) - (p.1 .1 as isize);
+
+    [data.offset(p.0, (x, y)), data.offset(p.1, (-x, -y))]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+fn count_antinodes(data: &Grid<char>) -> u64 {
+    let mut nodes = HashSet::new();
+
+    for (_, v) in antennas(data) {
+        for p in each_pair(&v) {
+            for n in antinodes(data, &p) {
+                nodes.insert(n);
+            }
+        }
+    }
+
+    nodes.len() as u64
+}
+
+fn distance(p: &PointPair) -> (isize, isize) {
+    let x = (p.0 .0 as isize) - (p.1 .0 as isize);
+    let y = (p.0 .1 as isize) - (p.1 .1 as isize);
+
+    (x, y)
+}
+
+fn generate_antinodes(
+    data: &Grid<char>,
+    curr: Point,
+    d: (isize, isize),
+) -> impl Iterator<Item = Point> + '_ {
+    std::iter::successors(Some(curr), move |&p| data.offset(p, d))
+}
+
+fn count_harmonics(data: &Grid<char>) -> u64 {
+    let mut nodes = HashSet::new();
+
+    for (_, v) in antennas(data) {
+        for p in each_pair(&v) {
+            let d = distance(&p);
+            nodes.extend(generate_antinodes(data, p.0, d));
+            nodes.extend(generate_antinodes(data, p.1, (-d.0, -d.1)));
+        }
+    }
+
+    nodes.len() as u64
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Grid<char>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_file(s)
+    }
+}
+
+impl PartA for Solution {
+    type A = u64;
+
+    fn part_a(v: &Self::Input) -> Self::A {
+        count_antinodes(v)
+    }
+}
+
+impl PartB for Solution {
+    type B = u64;
+
+    fn part_b(v: &Self::Input) -> Self::B {
+        count_harmonics(v)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_each_pair() {
+        let v = vec![(1usize, 2usize), (3, 4), (5, 6), (7, 8)];
+        let v2: Vec<_> = each_pair(&v).collect();
+        assert_eq!(
+            v2,
+            vec![
+                ((1, 2), (3, 4)),
+                ((1, 2), (5, 6)),
+                ((1, 2), (7, 8)),
+                ((3, 4), (5, 6)),
+                ((3, 4), (7, 8)),
+                ((5, 6), (7, 8))
+            ]
+        );
+    }
+}
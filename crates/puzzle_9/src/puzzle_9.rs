@@ -0,0 +1,184 @@
+use advent_core::{Day, ParseError, PartA, PartB};
+
+type Sector = Option<usize>;
+
+fn sectors(s: &[u32]) -> Vec<Sector> {
+    s.chunks(2)
+        .enumerate()
+        .flat_map(|(i, c)| {
+            let a = std::iter::repeat_n(Some(i), c[0] as usize);
+            if c.len() == 2 {
+                a.chain(std::iter::repeat_n(None, c[1] as usize))
+            } else {
+                a.chain(std::iter::repeat_n(None, 0))
+            }
+        })
+        .collect()
+}
+
+fn compact_sectors(data: &mut [Sector]) -> usize {
+    let mut i = 0usize;
+    // a disk of nothing but empty files
+    let Some(mut j) = data.len().checked_sub(1) else {
+        return 0;
+    };
+    loop {
+        while i < j {
+            if data[i].is_none() {
+                break;
+            }
+            i += 1;
+        }
+        while j > i {
+            if data[j].is_some() {
+                break;
+            }
+            j -= 1;
+        }
+        if i >= j {
+            break;
+        }
+        data.swap(i, j);
+    }
+
+    data.iter()
+        .map_while(|&b| b)
+        .enumerate()
+        .fold(0usize, |acc, (i, b)| acc + (i * b))
+}
+
+#[derive(Clone, Copy)]
+pub struct Block {
+    size: usize,
+    file: Option<usize>,
+}
+
+impl Block {
+    fn update(&mut self, rhs: Block) -> Option<Self> {
+        assert!(self.size >= rhs.size);
+        if self.size == rhs.size {
+            *self = rhs;
+            None
+        } else {
+            let ret = Block {
+                size: self.size - rhs.size,
+                file: None,
+            };
+            *self = rhs;
+            Some(ret)
+        }
+    }
+}
+
+fn blocks(s: &[u32]) -> Vec<Block> {
+    s.chunks(2)
+        .enumerate()
+        .flat_map(|(i, c)| {
+            let a = std::iter::once(Block {
+                size: c[0] as usize,
+                file: Some(i),
+            });
+            if let Some(b) = c.get(1) {
+                a.chain(std::iter::once(Block {
+                    size: *b as usize,
+                    file: None,
+                }))
+            } else {
+                a.chain(std::iter::once(Block {
+                    size: 0,
+                    file: None,
+                }))
+            }
+        })
+        .collect()
+}
+
+fn compact_files(data: &mut Vec<Block>) -> usize {
+    let mut i = 0usize;
+    let mut j = data.len() - 1;
+    'main: loop {
+        while i < j {
+            if data[i].file.is_none() {
+                break;
+            }
+            i += 1;
+        }
+        while j > i {
+            if data[j].file.is_some() {
+                break;
+            }
+            j -= 1;
+        }
+        if i >= j {
+            break;
+        }
+        if let Some(k) = (i..j).find(|&k| data[k].file.is_none() && data[k].size >= data[j].size) {
+            let f = data[j];
+            let new_block = data[k].update(f);
+            data[j].file = None;
+            if let Some(new_block) = new_block {
+                data.insert(k + 1, new_block);
+                j -= 1;
+            }
+            continue 'main;
+        }
+        j -= 1;
+    }
+
+    data.iter()
+        .flat_map(|b| std::iter::repeat_n(b.file, b.size))
+        .enumerate()
+        .filter_map(|(i, f)| f.map(|f| (i, f)))
+        .fold(0usize, |acc, (i, f)| acc + (i * f))
+}
+
+pub struct Solution;
+
+impl Day for Solution {
+    type Input = Vec<u32>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        let line = s.lines().next().unwrap_or_default();
+        if line.is_empty() {
+            return Err(ParseError::new(s, line, "a disk map of digits"));
+        }
+        line.char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .ok_or_else(|| ParseError::new(s, &line[i..i + c.len_utf8()], "a digit"))
+            })
+            .collect()
+    }
+}
+
+impl PartA for Solution {
+    type A = usize;
+
+    fn part_a(v: &Self::Input) -> Self::A {
+        compact_sectors(&mut sectors(v))
+    }
+}
+
+impl PartB for Solution {
+    type B = usize;
+
+    fn part_b(v: &Self::Input) -> Self::B {
+        compact_files(&mut blocks(v))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn empty_disks() {
+        assert_eq!(
+            Solution::parse("").unwrap_err().expected(),
+            "a disk map of digits"
+        );
+        assert!(Solution::parse("\n").is_err());
+        let v = Solution::parse("0\n").unwrap();
+        assert_eq!((Solution::part_a(&v), Solution::part_b(&v)), (0, 0));
+    }
+}
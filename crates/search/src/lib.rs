@@ -0,0 +1,496 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Anything that can be summed along a path. `Default` is the cost of standing still at the start.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+#[derive(Debug, Clone)]
+struct Label<S, C> {
+    cost: C,
+    // the first entry is the predecessor `path` follows, the rest are only kept by `dijkstra_all`
+    prev: Vec<S>,
+    settled: bool,
+}
+
+/// Everything a search found out: the best known cost to each state, how it was reached, and
+/// which goal states it stopped at.
+#[derive(Debug, Clone)]
+pub struct Paths<S, C> {
+    labels: HashMap<S, Label<S, C>>,
+    goals: Vec<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Paths<S, C> {
+    fn new(start: S) -> Self {
+        let label = Label {
+            cost: C::default(),
+            prev: vec![],
+            settled: false,
+        };
+        Self {
+            labels: HashMap::from([(start, label)]),
+            goals: vec![],
+        }
+    }
+
+    /// Records that `to` can be reached for `cost` via `from`. Returns whether that's an
+    /// improvement worth queueing.
+    fn relax(&mut self, from: &S, to: S, cost: C, all: bool) -> bool {
+        match self.labels.entry(to) {
+            Entry::Occupied(mut e) => {
+                let l = e.get_mut();
+                match cost.cmp(&l.cost) {
+                    Ordering::Less => {
+                        assert!(!l.settled, "negative edge or inconsistent heuristic");
+                        l.cost = cost;
+                        l.prev = vec![from.clone()];
+                        true
+                    }
+                    Ordering::Equal if all && !l.prev.contains(from) => {
+                        l.prev.push(from.clone());
+                        false
+                    }
+                    _ => false,
+                }
+            }
+            Entry::Vacant(e) => {
+                e.insert(Label {
+                    cost,
+                    prev: vec![from.clone()],
+                    settled: false,
+                });
+                true
+            }
+        }
+    }
+
+    /// Cost of the cheapest path to `s`, if the search got far enough to be sure of it.
+    pub fn cost(&self, s: &S) -> Option<C> {
+        self.labels.get(s).filter(|l| l.settled).map(|l| l.cost)
+    }
+
+    /// Every state with a known cheapest cost.
+    pub fn distances(&self) -> impl Iterator<Item = (&S, C)> {
+        self.labels
+            .iter()
+            .filter(|(_, l)| l.settled)
+            .map(|(s, l)| (s, l.cost))
+    }
+
+    /// The first goal state reached, which is also the cheapest.
+    pub fn goal(&self) -> Option<&S> {
+        self.goals.first()
+    }
+
+    /// Every goal state reached. Only [`dijkstra_all`] keeps going after the first one, to pick
+    /// up the others that tie with it.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// One cheapest path from the start to `s`, both ends included.
+    pub fn path(&self, s: &S) -> Option<Vec<S>> {
+        self.cost(s)?;
+        let mut path = vec![s.clone()];
+        while let Some(p) = self.labels[path.last().unwrap()].prev.first() {
+            path.push(p.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every cheapest path from the start to `s`. There can be exponentially many of these, so
+    /// prefer [`Paths::on_optimal_paths`] when only the states they cover matter.
+    pub fn all_paths(&self, s: &S) -> Vec<Vec<S>> {
+        if self.cost(s).is_none() {
+            return vec![];
+        }
+        let prev = &self.labels[s].prev;
+        if prev.is_empty() {
+            return vec![vec![s.clone()]];
+        }
+        prev.iter()
+            .flat_map(|p| self.all_paths(p))
+            .map(|mut path| {
+                path.push(s.clone());
+                path
+            })
+            .collect()
+    }
+
+    /// How many distinct cheapest paths lead from the start to any of `targets`, without
+    /// listing them like [`Paths::all_paths`] would. Each state's count is the sum of its
+    /// predecessors', worked out once. Pick a big integer `N` if there could be a lot of them.
+    /// Only [`dijkstra_all`] keeps every predecessor, and loops of free edges aren't counted
+    /// going round.
+    pub fn count_paths<'a, N>(&self, targets: impl IntoIterator<Item = &'a S>) -> N
+    where
+        S: 'a,
+        N: Clone + Add<Output = N> + From<u8>,
+    {
+        let mut counts: HashMap<S, N> = HashMap::new();
+        let mut entered = HashSet::new();
+        let mut total = N::from(0);
+        for t in targets.into_iter().filter(|t| self.cost(t).is_some()) {
+            // predecessors first, without recursing down what could be a very long path
+            let mut stack = vec![(t.clone(), false)];
+            while let Some((s, ready)) = stack.pop() {
+                let prev = &self.labels[&s].prev;
+                if ready {
+                    let n = match prev.is_empty() {
+                        true => N::from(1),
+                        false => prev.iter().fold(N::from(0), |n, p| {
+                            n + counts.get(p).cloned().unwrap_or(N::from(0))
+                        }),
+                    };
+                    counts.insert(s, n);
+                } else if entered.insert(s.clone()) {
+                    stack.push((s, true));
+                    stack.extend(prev.iter().map(|p| (p.clone(), false)));
+                }
+            }
+            total = total + counts[t].clone();
+        }
+        total
+    }
+
+    /// Every state that lies on at least one cheapest path to one of `targets`.
+    pub fn on_optimal_paths<'a>(&self, targets: impl IntoIterator<Item = &'a S>) -> HashSet<S>
+    where
+        S: 'a,
+    {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<_> = targets
+            .into_iter()
+            .filter(|t| self.cost(t).is_some())
+            .cloned()
+            .collect();
+        while let Some(s) = stack.pop() {
+            if seen.insert(s.clone()) {
+                stack.extend(self.labels[&s].prev.iter().cloned());
+            }
+        }
+        seen
+    }
+}
+
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.priority == rhs.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    // reversed so the BinaryHeap pops the cheapest first
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        rhs.priority.cmp(&self.priority)
+    }
+}
+
+fn search<S, C, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+    all: bool,
+) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut paths = Paths::new(start.clone());
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+    let mut best = None;
+
+    while let Some(Queued {
+        priority,
+        cost,
+        state,
+    }) = queue.pop()
+    {
+        if best.is_some_and(|b| priority > b) {
+            break;
+        }
+
+        let label = paths.labels.get_mut(&state).unwrap();
+        if label.settled || label.cost < cost {
+            // already popped with a better cost
+            continue;
+        }
+        label.settled = true;
+
+        if goal(&state) {
+            paths.goals.push(state);
+            if !all {
+                break;
+            }
+            best = Some(cost);
+            continue;
+        }
+
+        for (next, step) in neighbors(&state) {
+            let c = cost + step;
+            if paths.relax(&state, next.clone(), c, all) {
+                queue.push(Queued {
+                    priority: c + heuristic(&next),
+                    cost: c,
+                    state: next,
+                });
+            }
+        }
+    }
+    paths
+}
+
+/// Cheapest paths from `start`, stopping at the first state `goal` accepts. Pass `|_| false` as
+/// `goal` to get the distance to everything reachable.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(start, neighbors, |_| C::default(), goal, false)
+}
+
+/// Like [`dijkstra`] but remembers every predecessor that ties for the cheapest cost, and keeps
+/// going until every goal state tied with the first one has been found. This is what
+/// [`Paths::all_paths`] and [`Paths::on_optimal_paths`] need to see every optimal path.
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(start, neighbors, |_| C::default(), goal, true)
+}
+
+/// [`dijkstra`] guided by `heuristic`, which must never overestimate the remaining cost (and
+/// never drop by more than the cost of an edge) for the result to be optimal.
+pub fn astar<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    goal: impl FnMut(&S) -> bool,
+) -> Paths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(start, neighbors, heuristic, goal, false)
+}
+
+/// The `k` cheapest paths from `start` to states `goal` accepts, cheapest first, each with its
+/// cost. Ties all count, so the first few can cost the same. None of them go through a state
+/// twice. This is Yen's algorithm: each path after the first branches off one found before it,
+/// going the cheapest way it can from the branch point that doesn't retrace the shared start or
+/// take a step some earlier path already took from there.
+pub fn k_shortest<S, C, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+    k: usize,
+) -> Vec<(C, Vec<S>)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // the cheapest way on from `from` that keeps out of `avoid` and doesn't step from `from`
+    // straight into `cut`, with the total cost at each state
+    let mut spur = |from: &S, base: C, avoid: &HashSet<S>, cut: &HashSet<S>| {
+        let paths = dijkstra(
+            from.clone(),
+            |s: &S| {
+                let first = s == from;
+                neighbors(s)
+                    .into_iter()
+                    .filter(move |(n, _)| !(avoid.contains(n) || first && cut.contains(n)))
+            },
+            &mut goal,
+        );
+        let end = paths.goal()?;
+        let path = paths.path(end)?.into_iter().map(|s| {
+            let cost = base + paths.cost(&s).unwrap();
+            (s, cost)
+        });
+        Some(path.collect::<Vec<_>>())
+    };
+
+    let Some(first) = spur(&start, C::default(), &HashSet::new(), &HashSet::new()) else {
+        return vec![];
+    };
+    let mut found = vec![first];
+    let mut candidates: Vec<Vec<(S, C)>> = vec![];
+    while found.len() < k {
+        let last = found.last().unwrap().clone();
+        for j in 0..last.len() - 1 {
+            let root = &last[..=j];
+            let cut = found
+                .iter()
+                .filter(|p| {
+                    p.len() > j + 1
+                        && p[..=j]
+                            .iter()
+                            .map(|(s, _)| s)
+                            .eq(root.iter().map(|(s, _)| s))
+                })
+                .map(|p| p[j + 1].0.clone())
+                .collect();
+            let avoid = root[..j].iter().map(|(s, _)| s.clone()).collect();
+            if let Some(rest) = spur(&root[j].0, root[j].1, &avoid, &cut) {
+                let path: Vec<_> = root[..j].iter().cloned().chain(rest).collect();
+                if !candidates.contains(&path) {
+                    candidates.push(path);
+                }
+            }
+        }
+        // the first of the cheapest, so ties come out in the order they were found
+        let Some(i) = (0..candidates.len()).min_by_key(|&i| candidates[i].last().unwrap().1) else {
+            break;
+        };
+        found.push(candidates.remove(i));
+    }
+    found
+        .into_iter()
+        .map(|p| {
+            let cost = p.last().unwrap().1;
+            (cost, p.into_iter().map(|(s, _)| s).collect())
+        })
+        .collect()
+}
+
+/// [`dijkstra`] for when every step costs 1, which only needs a plain queue.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Paths<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = Paths::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        let label = paths.labels.get_mut(&state).unwrap();
+        label.settled = true;
+        let cost = label.cost;
+
+        if goal(&state) {
+            paths.goals.push(state);
+            break;
+        }
+
+        for next in neighbors(&state) {
+            if paths.relax(&state, next.clone(), cost + 1, false) {
+                queue.push_back(next);
+            }
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // a 3x3 open grid, every step costs 1
+    fn steps(&(i, j): &(i32, i32)) -> Vec<((i32, i32), u32)> {
+        [(i - 1, j), (i + 1, j), (i, j - 1), (i, j + 1)]
+            .into_iter()
+            .filter(|&(i, j)| (0..3).contains(&i) && (0..3).contains(&j))
+            .map(|p| (p, 1))
+            .collect()
+    }
+
+    #[test]
+    fn single_path() {
+        let paths = dijkstra((0, 0), steps, |&p| p == (2, 2));
+        assert_eq!(paths.goal(), Some(&(2, 2)));
+        assert_eq!(paths.cost(&(2, 2)), Some(4));
+        let path = paths.path(&(2, 2)).unwrap();
+        assert_eq!(path.len(), 5);
+        assert_eq!((path[0], path[4]), ((0, 0), (2, 2)));
+
+        let h = |&(i, j): &(i32, i32)| (2 - i + 2 - j) as u32;
+        let paths = astar((0, 0), steps, h, |&p| p == (2, 2));
+        assert_eq!(paths.cost(&(2, 2)), Some(4));
+
+        let paths = bfs((0, 0), |p| steps(p).into_iter().map(|(p, _)| p), |_| false);
+        assert_eq!(paths.distances().count(), 9);
+        assert_eq!(paths.cost(&(1, 2)), Some(3));
+    }
+
+    #[test]
+    fn every_optimal_path() {
+        let paths = dijkstra_all((0, 0), steps, |&p| p == (2, 2));
+        assert_eq!(paths.all_paths(&(2, 2)).len(), 6);
+        assert_eq!(paths.count_paths::<u64>([&(2, 2)]), 6);
+        assert_eq!(
+            paths.count_paths::<u64>([&(2, 2), &(1, 1), &(0, 0)]),
+            6 + 2 + 1
+        );
+        assert_eq!(paths.on_optimal_paths([&(2, 2)]).len(), 9);
+        assert_eq!(paths.on_optimal_paths([&(0, 2)]).len(), 3);
+    }
+
+    #[test]
+    fn k_cheapest() {
+        let found = k_shortest((0, 0), steps, |&p| p == (2, 2), 8);
+        let costs: Vec<_> = found.iter().map(|&(c, _)| c).collect();
+        // the 6 shortest, then the first that wind round
+        assert_eq!(costs, [4, 4, 4, 4, 4, 4, 6, 6]);
+        let distinct: HashSet<_> = found.iter().map(|(_, p)| p.clone()).collect();
+        assert_eq!(distinct.len(), 8);
+        assert!(found.iter().all(|(c, p)| p.len() as u32 == c + 1));
+        assert!(k_shortest((0, 0), steps, |&p| p == (5, 5), 3).is_empty());
+
+        // there are only 12 ways across without going back over a square
+        let found = k_shortest((0, 0), steps, |&p| p == (2, 2), 20);
+        assert_eq!(found.len(), 12);
+        for (_, p) in &found {
+            assert_eq!(p.iter().collect::<HashSet<_>>().len(), p.len());
+        }
+        let distinct: HashSet<_> = found.iter().map(|(_, p)| p.clone()).collect();
+        assert_eq!(distinct.len(), 12);
+        // and only one along a corridor, however many are asked for
+        let corridor =
+            |&(i, j): &(i32, i32)| steps(&(i, j)).into_iter().filter(|&((i, _), _)| i == 0);
+        assert_eq!(k_shortest((0, 0), corridor, |&p| p == (0, 2), 5).len(), 1);
+    }
+}
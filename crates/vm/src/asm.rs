@@ -0,0 +1,229 @@
+//! An assembler for the 3-bit computer, so test programs don't have to be written as opcodes.
+//!
+//! ```text
+//! ; registers default to 0
+//! A = 2024
+//!
+//! loop:   adv 1       ; combo operands take 0-3 or a register name
+//!         out A
+//!         jnz loop
+//! ```
+//!
+//! Comments start with `;` or `#`, labels end with `:` and can share a line with an
+//! instruction, and `bxc` doesn't need an operand.
+
+use crate::disasm::Combo;
+use crate::{Instruction, Register};
+use advent_core::ParseError;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+/// An assembled program, and what the registers start at. Displays as a puzzle input.
+/// Registers are as wide as [`Machine`](crate::Machine) goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    pub registers: [u128; 3],
+    pub code: Vec<u8>,
+}
+
+impl Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (r, v) in Register::ALL.iter().zip(self.registers) {
+            writeln!(f, "Register {r}: {v}")?;
+        }
+        let code: Vec<_> = self.code.iter().map(ToString::to_string).collect();
+        writeln!(f, "\nProgram: {}", code.join(","))
+    }
+}
+
+fn mnemonic(s: &str) -> Option<Instruction> {
+    Some(match s {
+        "adv" => Instruction::Adv,
+        "bxl" => Instruction::Bxl,
+        "bst" => Instruction::Bst,
+        "jnz" => Instruction::Jnz,
+        "bxc" => Instruction::Bxc,
+        "out" => Instruction::Out,
+        "bdv" => Instruction::Bdv,
+        "cdv" => Instruction::Cdv,
+        _ => return None,
+    })
+}
+
+fn register(s: &str) -> Option<Register> {
+    match s {
+        "A" | "a" => Some(Register::A),
+        "B" | "b" => Some(Register::B),
+        "C" | "c" => Some(Register::C),
+        _ => None,
+    }
+}
+
+/// Splits a line into its words, each a slice of `src` so errors can point at it.
+fn words(line: &str) -> Vec<&str> {
+    let code = line.split([';', '#']).next().unwrap_or_default();
+    code.split_whitespace().collect()
+}
+
+enum Item<'a> {
+    Register(Register, &'a str),
+    Instruction(Instruction, Option<&'a str>),
+}
+
+fn combo(src: &str, t: &str) -> Result<u8, ParseError> {
+    if let Some(r) = register(t) {
+        return Ok(4 + r as u8);
+    }
+    match t.parse::<u8>().map(Combo::new) {
+        Ok(Combo::Literal(n)) => Ok(n),
+        Ok(Combo::Reserved) if t == "7" => Err(ParseError::new(
+            src,
+            t,
+            "a combo operand other than the reserved 7",
+        )),
+        _ => Err(ParseError::new(src, t, "0 to 3 or a register A, B or C")),
+    }
+}
+
+/// Turns assembly source into a program, or the first thing wrong with it.
+pub fn assemble(src: &str) -> Result<Program, ParseError> {
+    // first pass finds where every label points
+    let mut items = vec![];
+    let mut labels = HashMap::new();
+    let mut ip = 0;
+    for line in src.lines() {
+        let mut ws = words(line).into_iter().peekable();
+        while let Some(label) = ws.next_if(|w| w.ends_with(':')) {
+            let name = &label[..label.len() - 1];
+            if labels.insert(name, ip).is_some() {
+                return Err(ParseError::new(src, label, "a label that isn't taken"));
+            }
+        }
+        let Some(first) = ws.next() else {
+            continue;
+        };
+        let item = if let Some(r) = register(first) {
+            if ws.next_if_eq(&"=").is_none() {
+                return Err(ParseError::new(src, first, "an instruction"));
+            }
+            let value = ws
+                .next()
+                .ok_or_else(|| ParseError::new(src, &first[first.len()..], "a register value"))?;
+            Item::Register(r, value)
+        } else {
+            let i = mnemonic(first).ok_or_else(|| ParseError::new(src, first, "an instruction"))?;
+            ip += 2;
+            Item::Instruction(i, ws.next())
+        };
+        if let Some(extra) = ws.next() {
+            return Err(ParseError::new(src, extra, "end of line"));
+        }
+        items.push((first, item));
+    }
+
+    let mut program = Program {
+        registers: [0; 3],
+        code: vec![],
+    };
+    for (first, item) in items {
+        let (i, operand) = match item {
+            Item::Register(r, value) => {
+                program.registers[r as usize] = value
+                    .parse()
+                    .map_err(|_| ParseError::new(src, value, "a register value"))?;
+                continue;
+            }
+            Item::Instruction(i, operand) => (i, operand),
+        };
+        let missing = || ParseError::new(src, &first[first.len()..], "an operand");
+        let op = match i {
+            Instruction::Bxc => match operand {
+                Some(t) => combo(src, t)?,
+                None => 0,
+            },
+            Instruction::Bxl => {
+                let t = operand.ok_or_else(missing)?;
+                t.parse()
+                    .ok()
+                    .filter(|&n| n < 8)
+                    .ok_or_else(|| ParseError::new(src, t, "a literal from 0 to 7"))?
+            }
+            Instruction::Jnz => {
+                let t = operand.ok_or_else(missing)?;
+                let target = match labels.get(t) {
+                    Some(&ip) => ip,
+                    None => t
+                        .parse()
+                        .map_err(|_| ParseError::new(src, t, "a label or an address"))?,
+                };
+                if target % 2 != 0 {
+                    return Err(ParseError::new(
+                        src,
+                        t,
+                        "an even jump target, odd ones land on an operand",
+                    ));
+                }
+                if target > 7 {
+                    return Err(ParseError::new(
+                        src,
+                        t,
+                        "a jump target from 0 to 6, jnz only takes 3 bits",
+                    ));
+                }
+                target as u8
+            }
+            _ => combo(src, operand.ok_or_else(missing)?)?,
+        };
+        program.code.extend([i as u8, op]);
+    }
+    Ok(program)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn labels_and_registers() {
+        let src = "
+            # the first example
+            start:
+            A = 729
+                   adv 1   ; halve A
+                   out a
+                   jnz start
+        ";
+        let p = assemble(src).unwrap();
+        assert_eq!(p.registers, [729, 0, 0]);
+        assert_eq!(p.code, [0, 1, 5, 4, 3, 0]);
+        assert_eq!(
+            p.to_string(),
+            "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n"
+        );
+    }
+
+    #[test]
+    fn bad_operands() {
+        let err = |src| assemble(src).unwrap_err().to_string();
+        assert_eq!(
+            err("bxl 1\nout 7\n"),
+            "line 2, column 5: expected a combo operand other than the reserved 7, found `7`"
+        );
+        assert_eq!(
+            err("jnz 3"),
+            "line 1, column 5: expected an even jump target, odd ones land on an operand, found `3`"
+        );
+        assert_eq!(
+            err("bst 5"),
+            "line 1, column 5: expected 0 to 3 or a register A, B or C, found `5`"
+        );
+        assert_eq!(
+            err("adv"),
+            "line 1, column 4: expected an operand, found end of input"
+        );
+        assert_eq!(
+            err("bxl 1\nbxl 1\nbxl 1\nbxl 1\nend: jnz end"),
+            "line 5, column 10: expected a jump target from 0 to 6, jnz only takes 3 bits, found `end`"
+        );
+    }
+}
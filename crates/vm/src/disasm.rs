@@ -0,0 +1,227 @@
+//! Turning programs back into something readable: a listing of mnemonics, or pseudo-code for
+//! the one shape of program the puzzle inputs all have.
+
+use crate::{Instruction, Register};
+use std::fmt::{self, Display};
+
+/// What a combo operand refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combo {
+    Literal(u8),
+    Register(Register),
+    /// 7, which valid programs never use.
+    Reserved,
+}
+
+impl Combo {
+    pub fn new(op: u8) -> Self {
+        match op {
+            0..=3 => Combo::Literal(op),
+            4 => Combo::Register(Register::A),
+            5 => Combo::Register(Register::B),
+            6 => Combo::Register(Register::C),
+            _ => Combo::Reserved,
+        }
+    }
+}
+
+impl Display for Combo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Combo::Literal(n) => write!(f, "{n}"),
+            Combo::Register(r) => write!(f, "{r}"),
+            Combo::Reserved => f.write_str("7 (reserved)"),
+        }
+    }
+}
+
+/// An instruction's operand, read the way that instruction reads it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Literal(u8),
+    Combo(Combo),
+    /// `bxc` reads its operand and then does nothing with it.
+    Ignored(u8),
+}
+
+impl Operand {
+    pub fn new(instruction: Instruction, op: u8) -> Self {
+        match instruction {
+            Instruction::Bxl | Instruction::Jnz => Operand::Literal(op),
+            Instruction::Bxc => Operand::Ignored(op),
+            _ => Operand::Combo(Combo::new(op)),
+        }
+    }
+}
+
+/// One line of a listing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line {
+    pub ip: usize,
+    /// `Err` with the opcode if there's no instruction for it, which is listed as raw bytes.
+    pub instruction: Result<Instruction, u8>,
+    /// `None` when the program ends right after the opcode. Always a literal after an opcode
+    /// that isn't one.
+    pub operand: Option<Operand>,
+}
+
+impl Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let instruction = match self.instruction {
+            Ok(i) => i,
+            Err(op) => {
+                write!(f, "{:>4}  .byte {op}", self.ip)?;
+                if let Some(Operand::Literal(n)) = self.operand {
+                    write!(f, ", {n}")?;
+                }
+                return Ok(());
+            }
+        };
+        write!(f, "{:>4}  {instruction}", self.ip)?;
+        match self.operand {
+            Some(Operand::Literal(n)) => write!(f, " {n}"),
+            Some(Operand::Combo(c)) => write!(f, " {c}"),
+            Some(Operand::Ignored(_)) => Ok(()),
+            None => f.write_str(" (missing operand)"),
+        }
+    }
+}
+
+/// Every instruction in `program`, in order, with any opcode of 8 and up as a `.byte` line.
+pub fn disassemble(program: &[u8]) -> Vec<Line> {
+    program
+        .chunks(2)
+        .enumerate()
+        .map(|(i, c)| {
+            let instruction = Instruction::try_from(c[0]).map_err(|()| c[0]);
+            Line {
+                ip: i * 2,
+                instruction,
+                operand: c.get(1).map(|&op| match instruction {
+                    Ok(instruction) => Operand::new(instruction, op),
+                    Err(_) => Operand::Literal(op),
+                }),
+            }
+        })
+        .collect()
+}
+
+fn value(c: Combo) -> String {
+    match c {
+        Combo::Literal(n) => n.to_string(),
+        Combo::Register(r) => r.to_string().to_lowercase(),
+        Combo::Reserved => "<reserved>".to_owned(),
+    }
+}
+
+fn low_bits(c: Combo) -> String {
+    match c {
+        Combo::Literal(n) => n.to_string(),
+        c => format!("{} % 8", value(c)),
+    }
+}
+
+/// `decompile` has already turned away bad opcodes.
+fn statement(line: &Line) -> String {
+    let Some(op) = line.operand else {
+        return "halt".to_owned();
+    };
+    match (line.instruction.unwrap(), op) {
+        (Instruction::Adv, Operand::Combo(c)) => format!("a >>= {}", value(c)),
+        (Instruction::Bdv, Operand::Combo(c)) => format!("b = a >> {}", value(c)),
+        (Instruction::Cdv, Operand::Combo(c)) => format!("c = a >> {}", value(c)),
+        (Instruction::Bst, Operand::Combo(c)) => format!("b = {}", low_bits(c)),
+        (Instruction::Out, Operand::Combo(c)) => format!("out({})", low_bits(c)),
+        (Instruction::Bxl, Operand::Literal(n)) => format!("b ^= {n}"),
+        (Instruction::Jnz, Operand::Literal(n)) => format!("if a != 0 goto {n}"),
+        (Instruction::Bxc, _) => "b ^= c".to_owned(),
+        // `Operand::new` decides the kind from the instruction
+        _ => unreachable!(),
+    }
+}
+
+/// Pseudo-code for `program`, if it's straight-line code or one loop that jumps back to the
+/// start from its last instruction, which is what every puzzle input looks like. `None` for
+/// anything with other jumps or an opcode of 8 or more in it.
+pub fn decompile(program: &[u8]) -> Option<String> {
+    let lines = disassemble(program);
+    let (body, looped) = match lines.split_last() {
+        Some((
+            Line {
+                instruction: Ok(Instruction::Jnz),
+                operand: Some(Operand::Literal(0)),
+                ..
+            },
+            body,
+        )) => (body, true),
+        _ => (&lines[..], false),
+    };
+    if body
+        .iter()
+        .any(|l| l.instruction.is_err() || l.instruction == Ok(Instruction::Jnz))
+    {
+        return None;
+    }
+
+    let indent = if looped { "    " } else { "" };
+    let mut out = String::new();
+    if looped {
+        out.push_str("do {\n");
+    }
+    for l in body {
+        out.push_str(&format!("{indent}{};\n", statement(l)));
+    }
+    if looped {
+        out.push_str("} while a != 0\n");
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn listing() {
+        let lines: Vec<_> = disassemble(&[2, 4, 1, 3, 4, 0, 5, 7, 3, 0, 6])
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "   0  bst A",
+                "   2  bxl 3",
+                "   4  bxc",
+                "   6  out 7 (reserved)",
+                "   8  jnz 0",
+                "  10  bdv (missing operand)",
+            ]
+        );
+        let lines: Vec<_> = disassemble(&[9, 4, 5, 4, 12])
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(lines, ["   0  .byte 9, 4", "   2  out A", "   4  .byte 12"]);
+    }
+
+    #[test]
+    fn single_loop() {
+        let program = [2, 4, 1, 3, 7, 5, 4, 0, 1, 3, 0, 3, 5, 5, 3, 0];
+        assert_eq!(
+            decompile(&program).unwrap(),
+            "do {
+    b = a % 8;
+    b ^= 3;
+    c = a >> b;
+    b ^= c;
+    b ^= 3;
+    a >>= 3;
+    out(b % 8);
+} while a != 0
+"
+        );
+        assert_eq!(decompile(&[5, 4, 3, 4, 3, 0]), None);
+        assert_eq!(decompile(&[5, 4, 9, 4, 3, 0]), None);
+    }
+}
@@ -0,0 +1,402 @@
+//! The 3-bit computer from day 17, as something that can be stepped through, stopped at
+//! breakpoints and traced.
+
+use std::collections::BTreeSet;
+use std::fmt::{self, Display};
+use std::ops::BitXor;
+
+pub mod asm;
+pub mod disasm;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Instruction {
+    Adv = 0,
+    Bxl = 1,
+    Bst = 2,
+    Jnz = 3,
+    Bxc = 4,
+    Out = 5,
+    Bdv = 6,
+    Cdv = 7,
+}
+
+impl TryFrom<u8> for Instruction {
+    type Error = ();
+    fn try_from(i: u8) -> Result<Self, <Self as TryFrom<u8>>::Error> {
+        Ok(match i {
+            0 => Self::Adv,
+            1 => Self::Bxl,
+            2 => Self::Bst,
+            3 => Self::Jnz,
+            4 => Self::Bxc,
+            5 => Self::Out,
+            6 => Self::Bdv,
+            7 => Self::Cdv,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Adv => "adv",
+            Self::Bxl => "bxl",
+            Self::Bst => "bst",
+            Self::Jnz => "jnz",
+            Self::Bxc => "bxc",
+            Self::Out => "out",
+            Self::Bdv => "bdv",
+            Self::Cdv => "cdv",
+        };
+        f.pad(name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Register {
+    A,
+    B,
+    C,
+}
+
+impl Register {
+    pub const ALL: [Register; 3] = [Register::A, Register::B, Register::C];
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Register::A => "A",
+            Register::B => "B",
+            Register::C => "C",
+        })
+    }
+}
+
+/// What the registers hold. Every puzzle input fits in a `u64`, `u128` is there for
+/// programs started with bigger values.
+pub trait Word:
+    Copy + Eq + Default + fmt::Debug + Display + BitXor<Output = Self> + From<u8>
+{
+    /// `self >> n`, which is 0 once `n` is the width or more.
+    fn shr(self, n: Self) -> Self;
+    /// The lowest three bits.
+    fn low_bits(self) -> u8;
+}
+
+macro_rules! words {
+    ($($t:ty),*) => {$(
+        impl Word for $t {
+            fn shr(self, n: Self) -> Self {
+                u32::try_from(n)
+                    .ok()
+                    .and_then(|n| self.checked_shr(n))
+                    .unwrap_or(0)
+            }
+
+            fn low_bits(self) -> u8 {
+                (self & 0x7) as u8
+            }
+        }
+    )*};
+}
+
+words!(u64, u128);
+
+/// Everything one instruction did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step<W = u64> {
+    pub ip: usize,
+    pub instruction: Instruction,
+    pub operand: u8,
+    /// Registers that changed, with their old and new values.
+    pub changes: Vec<(Register, W, W)>,
+    pub output: Option<u8>,
+}
+
+impl<W: Word> Display for Step<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = disasm::Line {
+            ip: self.ip,
+            instruction: Ok(self.instruction),
+            operand: Some(disasm::Operand::new(self.instruction, self.operand)),
+        };
+        write!(f, "{line}")?;
+        for (r, old, new) in &self.changes {
+            write!(f, "  {r}: {old} -> {new}")?;
+        }
+        if let Some(out) = self.output {
+            write!(f, "  out: {out}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An instruction the machine can't carry out. The instruction pointer stays on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// The instruction at this address has the reserved combo operand 7.
+    ReservedCombo(usize),
+    /// There's no instruction for the opcode at this address, it isn't 3 bits.
+    BadOpcode(usize),
+}
+
+impl Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fault::ReservedCombo(ip) => write!(f, "reserved combo operand 7 at {ip}"),
+            Fault::BadOpcode(ip) => write!(f, "no instruction for opcode at {ip}"),
+        }
+    }
+}
+
+impl std::error::Error for Fault {}
+
+/// Why [`Machine::run`] gave control back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// The instruction pointer ran off the end of the program.
+    Halted,
+    /// About to run the instruction at this address.
+    Breakpoint(usize),
+    /// The instruction budget ran out, which usually means the program loops forever.
+    OutOfBudget,
+    /// Stuck on an instruction it can't run.
+    Fault(Fault),
+}
+
+#[derive(Debug, Clone)]
+pub struct Machine<W = u64> {
+    registers: [W; 3],
+    ip: usize,
+    program: Vec<u8>,
+    output: Vec<u8>,
+    breakpoints: BTreeSet<usize>,
+    budget: Option<u64>,
+    steps: u64,
+    trace: Option<Vec<Step<W>>>,
+}
+
+impl<W: Word> Machine<W> {
+    pub fn new(program: Vec<u8>, [a, b, c]: [W; 3]) -> Self {
+        Self {
+            registers: [a, b, c],
+            ip: 0,
+            program,
+            output: vec![],
+            breakpoints: BTreeSet::new(),
+            budget: None,
+            steps: 0,
+            trace: None,
+        }
+    }
+
+    /// Stops [`run`](Self::run) and [`run_until_halt`](Self::run_until_halt) after `n`
+    /// instructions in total.
+    pub fn with_budget(mut self, n: u64) -> Self {
+        self.budget = Some(n);
+        self
+    }
+
+    /// Keeps every [`Step`] taken from here on, see [`trace`](Self::trace).
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(vec![]);
+        self
+    }
+
+    pub fn add_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.insert(ip);
+    }
+
+    pub fn remove_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.remove(&ip);
+    }
+
+    pub fn register(&self, r: Register) -> W {
+        self.registers[r as usize]
+    }
+
+    pub fn set_register(&mut self, r: Register, value: W) {
+        self.registers[r as usize] = value;
+    }
+
+    pub fn registers(&self) -> [W; 3] {
+        self.registers
+    }
+
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// Instructions run so far.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Empty unless the machine was made [`with_trace`](Self::with_trace).
+    pub fn trace(&self) -> &[Step<W>] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    /// An opcode without an operand after it halts too.
+    pub fn halted(&self) -> bool {
+        self.ip + 1 >= self.program.len()
+    }
+
+    fn out_of_budget(&self) -> bool {
+        self.budget.is_some_and(|n| self.steps >= n)
+    }
+
+    /// The whole value, only `bst` and `out` keep just the low bits.
+    fn read_combo(&self, op: u8) -> Result<W, Fault> {
+        match op {
+            0..=3 => Ok(op.into()),
+            4..=6 => Ok(self.registers[op as usize - 4]),
+            _ => Err(Fault::ReservedCombo(self.ip)),
+        }
+    }
+
+    /// A divided by 2 to the power of the operand, which is a shift.
+    fn dv(&self, op: u8) -> Result<W, Fault> {
+        Ok(self.registers[0].shr(self.read_combo(op)?))
+    }
+
+    /// Runs one instruction, or returns `None` if the machine has halted. Breakpoints and the
+    /// budget don't apply here.
+    pub fn step(&mut self) -> Result<Option<Step<W>>, Fault> {
+        if self.halted() {
+            return Ok(None);
+        }
+        let ip = self.ip;
+        let instruction = self.program[ip]
+            .try_into()
+            .map_err(|()| Fault::BadOpcode(ip))?;
+        let operand = self.program[ip + 1];
+        let before = self.registers;
+        let mut output = None;
+
+        let [mut a, mut b, mut c] = before;
+        let mut next = ip + 2;
+        match instruction {
+            Instruction::Adv => a = self.dv(operand)?,
+            Instruction::Bxl => b = b ^ operand.into(),
+            Instruction::Bst => b = self.read_combo(operand)?.low_bits().into(),
+            Instruction::Jnz => {
+                if a != W::default() {
+                    next = operand as usize;
+                }
+            }
+            Instruction::Bxc => b = b ^ c,
+            Instruction::Out => output = Some(self.read_combo(operand)?.low_bits()),
+            Instruction::Bdv => b = self.dv(operand)?,
+            Instruction::Cdv => c = self.dv(operand)?,
+        }
+        self.ip = next;
+        self.registers = [a, b, c];
+        self.output.extend(output);
+        self.steps += 1;
+
+        let step = Step {
+            ip,
+            instruction,
+            operand,
+            changes: Register::ALL
+                .into_iter()
+                .filter(|&r| before[r as usize] != self.registers[r as usize])
+                .map(|r| (r, before[r as usize], self.registers[r as usize]))
+                .collect(),
+            output,
+        };
+        if let Some(trace) = &mut self.trace {
+            trace.push(step.clone());
+        }
+        Ok(Some(step))
+    }
+
+    /// Runs until the program halts, the budget runs out, or it gets to a breakpoint. The
+    /// instruction it starts on always runs, so calling this again carries on past the
+    /// breakpoint it last stopped at.
+    pub fn run(&mut self) -> Stop {
+        let mut first = true;
+        loop {
+            if self.halted() {
+                return Stop::Halted;
+            }
+            if !first && self.breakpoints.contains(&self.ip) {
+                return Stop::Breakpoint(self.ip);
+            }
+            if self.out_of_budget() {
+                return Stop::OutOfBudget;
+            }
+            if let Err(fault) = self.step() {
+                return Stop::Fault(fault);
+            }
+            first = false;
+        }
+    }
+
+    /// [`run`](Self::run), but straight through any breakpoints. Never stops with
+    /// [`Stop::Breakpoint`].
+    pub fn run_until_halt(&mut self) -> Stop {
+        loop {
+            if self.halted() {
+                return Stop::Halted;
+            }
+            if self.out_of_budget() {
+                return Stop::OutOfBudget;
+            }
+            if let Err(fault) = self.step() {
+                return Stop::Fault(fault);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let mut m = Machine::new(vec![0, 1, 5, 4, 3, 0], [729u64, 0, 0]);
+        assert_eq!(m.run_until_halt(), Stop::Halted);
+        assert_eq!(m.output(), [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+        assert_eq!(m.register(Register::A), 0);
+    }
+
+    #[test]
+    fn breakpoints_and_budget() {
+        let mut m = Machine::new(vec![0, 1, 5, 4, 3, 0], [729u64, 0, 0]).with_trace();
+        m.add_breakpoint(2);
+        assert_eq!(m.run(), Stop::Breakpoint(2));
+        assert_eq!(m.register(Register::A), 364);
+        assert_eq!(m.run(), Stop::Breakpoint(2));
+        assert_eq!(m.output(), [4]);
+        assert_eq!(
+            m.trace()[0],
+            Step {
+                ip: 0,
+                instruction: Instruction::Adv,
+                operand: 1,
+                changes: vec![(Register::A, 729, 364)],
+                output: None,
+            }
+        );
+
+        // jumps back to the start forever since A never changes
+        let mut m = Machine::new(vec![5, 4, 3, 0], [1u64, 0, 0]).with_budget(100);
+        assert_eq!(m.run_until_halt(), Stop::OutOfBudget);
+        assert_eq!(m.steps(), 100);
+        assert_eq!(m.output().len(), 50);
+    }
+}
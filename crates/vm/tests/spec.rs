@@ -0,0 +1,99 @@
+//! The small examples from the day 17 puzzle text, plus the corners of the spec it leaves to
+//! the reader.
+
+use vm::{Fault, Machine, Register, Stop};
+
+fn run(program: &[u8], registers: [u64; 3]) -> Machine {
+    let mut m = Machine::new(program.to_vec(), registers);
+    assert_eq!(m.run_until_halt(), Stop::Halted);
+    m
+}
+
+#[test]
+fn bst_keeps_the_low_bits() {
+    let m = run(&[2, 6], [0, 0, 9]);
+    assert_eq!(m.registers(), [0, 1, 9]);
+}
+
+#[test]
+fn out_with_literals_and_registers() {
+    let m = run(&[5, 0, 5, 1, 5, 4], [10, 0, 0]);
+    assert_eq!(m.output(), [0, 1, 2]);
+}
+
+#[test]
+fn adv_loop() {
+    let m = run(&[0, 1, 5, 4, 3, 0], [2024, 0, 0]);
+    assert_eq!(m.output(), [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+    assert_eq!(m.register(Register::A), 0);
+}
+
+#[test]
+fn bxl() {
+    let m = run(&[1, 7], [0, 29, 0]);
+    assert_eq!(m.register(Register::B), 26);
+}
+
+#[test]
+fn bxc_ignores_its_operand() {
+    let m = run(&[4, 0], [0, 2024, 43690]);
+    assert_eq!(m.register(Register::B), 44354);
+}
+
+#[test]
+fn first_example() {
+    let m = run(&[0, 1, 5, 4, 3, 0], [729, 0, 0]);
+    assert_eq!(m.output(), [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+}
+
+#[test]
+fn dv_shifts_by_the_whole_operand() {
+    // B is 9, so these are shifts by 9 and not by 1
+    let m = run(&[0, 5, 7, 5], [1 << 12, 9, 0]);
+    assert_eq!(m.registers(), [1 << 3, 9, 0]);
+
+    // shifting by at least the width leaves nothing
+    let m = run(&[6, 5, 7, 6], [u64::MAX, 64, 1 << 40]);
+    assert_eq!(m.registers(), [u64::MAX, 0, 0]);
+}
+
+#[test]
+fn jnz_only_jumps_when_a_is_nonzero() {
+    let m = run(&[3, 4, 5, 1, 5, 2], [0, 0, 0]);
+    assert_eq!(m.output(), [1, 2]);
+    let m = run(&[3, 4, 5, 1, 5, 2], [1, 0, 0]);
+    assert_eq!(m.output(), [2]);
+}
+
+#[test]
+fn reserved_combo_operand() {
+    let mut m = Machine::new(vec![5, 1, 5, 7, 5, 2], [0u64, 0, 0]);
+    assert_eq!(m.run_until_halt(), Stop::Fault(Fault::ReservedCombo(2)));
+    assert_eq!(m.output(), [1]);
+    assert_eq!(m.ip(), 2);
+    assert_eq!(m.step(), Err(Fault::ReservedCombo(2)));
+    // only combo operands are reserved
+    assert_eq!(run(&[1, 7], [0, 0, 0]).register(Register::B), 7);
+}
+
+#[test]
+fn bad_opcode() {
+    let mut m = Machine::new(vec![5, 1, 8, 0, 5, 2], [0u64, 0, 0]);
+    assert_eq!(m.run_until_halt(), Stop::Fault(Fault::BadOpcode(2)));
+    assert_eq!(m.output(), [1]);
+    assert_eq!(m.ip(), 2);
+    assert_eq!(m.step(), Err(Fault::BadOpcode(2)));
+}
+
+#[test]
+fn wide_registers() {
+    let mut m = Machine::new(vec![0, 3, 5, 4, 3, 0], [1u128 << 90, 0, 0]);
+    assert_eq!(m.run_until_halt(), Stop::Halted);
+    let mut expected = vec![0; 29];
+    expected.extend([1, 0]);
+    assert_eq!(m.output(), expected);
+
+    let mut m = Machine::new(vec![0, 5], [u128::MAX, 100, 0]);
+    assert_eq!(m.run_until_halt(), Stop::Halted);
+    assert_eq!(m.registers(), [u128::MAX >> 100, 100, 0]);
+}